@@ -0,0 +1,241 @@
+//! Generates the bundled icon set from the SVG sources in `icons/`.
+//!
+//! Only runs with the `tabler` feature, every `icons/tabler/outline/<name>.svg`
+//! becomes a `<name>()` constructor in `components::icon::tabler` and every
+//! `icons/tabler/filled/<name>.svg` a `<name>_filled()` one. The list of bundled
+//! icons for the module docs is generated alongside.
+//!
+//! Shapes are converted into typed `SvgElement`s, the build fails on elements
+//! and attributes which can't be converted.
+
+use std::{env, fs, path::Path};
+
+/// Rust keywords which can't be used as function names as is.
+const KEYWORDS: &[&str] = &[
+    "as", "box", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "try", "yield",
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=icons");
+    if env::var_os("CARGO_FEATURE_TABLER").is_none() {
+        return;
+    }
+    let out = env::var("OUT_DIR").unwrap();
+    let out = Path::new(&out);
+    let outline = icons("icons/tabler/outline", "");
+    let filled = icons("icons/tabler/filled", "-filled");
+    fs::write(out.join("tabler.rs"), tabler(&outline, &filled)).unwrap();
+    fs::write(out.join("tabler.md"), tabler_docs(&outline, &filled)).unwrap();
+}
+
+/// Tabler icon: (name, name on tabler.io, style function, elements).
+type TablerIcon = (String, String, &'static str, Vec<String>);
+
+/// Reads the icons of a Tabler directory, names get the `suffix`.
+fn icons(dir: &str, suffix: &str) -> Vec<TablerIcon> {
+    let style = if suffix.is_empty() {
+        "outline"
+    } else {
+        "filled"
+    };
+    let mut icons: Vec<TablerIcon> = fs::read_dir(dir)
+        .unwrap()
+        .map(|x| x.unwrap().path())
+        .filter(|x| x.extension().is_some_and(|x| x == "svg"))
+        .map(|x| {
+            let name = x.file_stem().unwrap().to_string_lossy().to_string();
+            let svg = fs::read_to_string(&x).unwrap();
+            let elements = elements(&svg).unwrap_or_else(|e| panic!("{}: {e}", x.display()));
+            (format!("{name}{suffix}"), name, style, elements)
+        })
+        .collect();
+    icons.sort();
+    icons
+}
+
+/// Generates constructors and the name index of the Tabler icons.
+fn tabler(outline: &[TablerIcon], filled: &[TablerIcon]) -> String {
+    let mut icons: Vec<&TablerIcon> = outline.iter().chain(filled).collect();
+    icons.sort();
+
+    let mut out = String::new();
+    for (name, page, style, elements) in &icons {
+        out.push_str(&format!(
+            "/// [Tabler Icon](https://tabler.io/icons/icon/{page}) `{name}`\n#[inline]\npub fn {}() -> Icon {{\n    {style}(vec![{}])\n}}\n\n",
+            ident(name),
+            elements.join(", ")
+        ));
+    }
+    out.push_str("/// Every bundled icon by name, sorted by name\n");
+    out.push_str("pub static ICONS: &[(&str, IconConstructor)] = &[\n");
+    for (name, ..) in &icons {
+        out.push_str(&format!("    ({name:?}, {}),\n", ident(name)));
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// Generates the list of bundled icons for the module docs.
+fn tabler_docs(outline: &[TablerIcon], filled: &[TablerIcon]) -> String {
+    let list = |icons: &[TablerIcon]| {
+        icons
+            .iter()
+            .map(|(name, ..)| format!("[`{name}`]({})", ident(name)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "# Bundled icons\n\nOutline ({}): {}\n\nFilled ({}): {}\n",
+        outline.len(),
+        list(outline),
+        filled.len(),
+        list(filled)
+    )
+}
+
+/// Converts every visible shape into a `SvgElement` expression in order of appearance.
+///
+/// Tabler frame `<path stroke="none" d="M0 0h24v24H0z" fill="none"/>` is skipped,
+/// unsupported elements and attributes fail the build instead of being dropped.
+fn elements(svg: &str) -> Result<Vec<String>, String> {
+    let mut out = vec![];
+    for tag in svg.split('<').skip(1) {
+        let tag = tag.split_once('>').ok_or("unclosed tag")?.0;
+        let tag = tag.trim_end_matches('/');
+        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+        if matches!(name, "svg" | "/svg") || name.starts_with(['?', '!']) {
+            continue;
+        }
+        let attributes = parse_attributes(attributes)?;
+        let attribute = |x: &str| attributes.iter().find(|(k, _)| k == x).map(|(_, v)| v);
+        let number = |x: &str| -> Result<f64, String> {
+            let value = attribute(x).map_or("0", |x| x.as_str());
+            value
+                .parse()
+                .map_err(|_| format!("invalid <{name} {x}=\"{value}\">"))
+        };
+        let geometry: &[&str] = match name {
+            "path" if attribute("stroke").is_some_and(|x| x == "none") => continue,
+            "path" => &["d"],
+            "circle" => &["cx", "cy", "r"],
+            "ellipse" => &["cx", "cy", "rx", "ry"],
+            "rect" => &["x", "y", "width", "height", "rx", "ry"],
+            "line" => &["x1", "y1", "x2", "y2"],
+            "polyline" | "polygon" => &["points"],
+            _ => return Err(format!("unsupported element <{name}>")),
+        };
+        let mut element = match name {
+            "path" => format!(
+                "SvgElement::path({:?})",
+                attribute("d").ok_or("<path> without d")?
+            ),
+            "circle" => format!(
+                "SvgElement::circle({:?}, {:?}, {:?})",
+                number("cx")?,
+                number("cy")?,
+                number("r")?
+            ),
+            "ellipse" => {
+                let (cx, cy, rx, ry) = (number("cx")?, number("cy")?, number("rx")?, number("ry")?);
+                let (x, d) = (cx - rx, rx * 2.0);
+                let d = format!("M{x} {cy}a{rx} {ry} 0 1 0 {d} 0a{rx} {ry} 0 1 0 -{d} 0");
+                format!("SvgElement::path({d:?})")
+            }
+            "rect" => {
+                let rx = attribute("rx").map(|_| number("rx")).transpose()?;
+                let ry = attribute("ry").map(|_| number("ry")).transpose()?;
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => (0.0, 0.0),
+                };
+                format!(
+                    "SvgElement::rounded_rect({:?}, {:?}, {:?}, {:?}, {rx:?}, {ry:?})",
+                    number("x")?,
+                    number("y")?,
+                    number("width")?,
+                    number("height")?
+                )
+            }
+            "line" => format!(
+                "SvgElement::line({:?}, {:?}, {:?}, {:?})",
+                number("x1")?,
+                number("y1")?,
+                number("x2")?,
+                number("y2")?
+            ),
+            _ => {
+                let points = attribute("points").ok_or(format!("<{name}> without points"))?;
+                let points: Vec<&str> = points
+                    .split(|x: char| x == ',' || x.is_whitespace())
+                    .filter(|x| !x.is_empty())
+                    .collect();
+                let pairs = points.chunks_exact(2);
+                if points.is_empty() || !pairs.remainder().is_empty() {
+                    return Err(format!("invalid <{name} points>"));
+                }
+                let mut d: String = pairs
+                    .enumerate()
+                    .map(|(i, x)| format!("{}{} {}", if i == 0 { 'M' } else { 'L' }, x[0], x[1]))
+                    .collect();
+                if name == "polygon" {
+                    d.push('z');
+                }
+                format!("SvgElement::path({d:?})")
+            }
+        };
+        for (key, value) in &attributes {
+            match key.as_str() {
+                x if geometry.contains(&x) => {}
+                "fill" | "stroke" | "transform" => element.push_str(&format!(".{key}({value:?})")),
+                "stroke-width" | "opacity" => {
+                    let value: f64 = value.parse().map_err(|_| format!("invalid {key}"))?;
+                    element.push_str(&format!(".{}({value:?})", key.replace('-', "_")));
+                }
+                _ => return Err(format!("unsupported attribute <{name} {key}>")),
+            }
+        }
+        out.push(element);
+    }
+    Ok(out)
+}
+
+/// Parses `key="value"` attributes of a tag.
+fn parse_attributes(mut tag: &str) -> Result<Vec<(String, String)>, String> {
+    let mut out = vec![];
+    loop {
+        tag = tag.trim_start();
+        if tag.is_empty() {
+            return Ok(out);
+        }
+        let (key, rest) = tag
+            .split_once("=\"")
+            .ok_or(format!("invalid attribute {tag}"))?;
+        let (value, rest) = rest
+            .split_once('"')
+            .ok_or(format!("unclosed attribute {key}"))?;
+        out.push((key.trim().to_string(), value.to_string()));
+        tag = rest;
+    }
+}
+
+/// Converts an icon name into a function name, e.g. `brand-rust` => `brand_rust`.
+///
+/// Names starting with a digit and keywords which can't be raw identifiers
+/// (`self`, `super`, `crate`, `Self`) get the `icon_` prefix, other keywords are raw.
+fn ident(name: &str) -> String {
+    let ident = name.replace('-', "_");
+    if ident.starts_with(|x: char| x.is_ascii_digit())
+        || matches!(ident.as_str(), "self" | "super" | "crate" | "Self")
+    {
+        format!("icon_{ident}")
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else {
+        ident
+    }
+}
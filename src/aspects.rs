@@ -2,19 +2,29 @@
 //!
 //! This module defines base aspects and their associated types.
 
+pub mod attributes;
 pub mod border;
 pub mod color;
 pub mod font;
+pub mod font_face;
 pub mod order;
+pub mod overflow;
+pub mod semantic;
 pub mod spaceing;
 pub mod svg;
+pub mod token;
 
+pub use attributes::*;
 pub use border::*;
 pub use color::*;
 pub use font::*;
+pub use font_face::*;
 pub use order::*;
+pub use overflow::*;
+pub use semantic::*;
 pub use spaceing::*;
 pub use svg::*;
+pub use token::*;
 
 /// Indicates whether an element should be displayed.
 ///
@@ -0,0 +1,33 @@
+//! Identification and metadata of components
+//!
+//! **Note**: These are applicable only for HTML/Leptos render attributes and will be ignored for other render types.
+
+use std::collections::BTreeMap;
+
+/// Unique id of an element, e.g. HTML `id`
+pub type Id = Option<String>;
+
+/// Class list of an element, e.g. HTML `class`
+pub type Classes = Vec<String>;
+
+/// Custom attributes by full name, e.g. `data-*` and `aria-*`
+pub type Attributes = BTreeMap<String, String>;
+
+/// Returns the full name of a custom attribute, e.g. `data-repo-id`.
+///
+/// The name is lowercased, characters other than `a-z`, `0-9`, `-`, `_` and `.` are dropped.
+///
+/// ```rust
+/// use zen_rs::aspects::attribute_name;
+///
+/// assert_eq!(attribute_name("data", "Repo-Id"), "data-repo-id");
+/// assert_eq!(attribute_name("aria", "label\" onclick"), "aria-labelonclick");
+/// ```
+pub fn attribute_name(prefix: &str, name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|x| x.to_ascii_lowercase())
+        .filter(|x| x.is_ascii_alphanumeric() || matches!(x, '-' | '_' | '.'))
+        .collect();
+    format!("{prefix}-{name}")
+}
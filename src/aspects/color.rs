@@ -18,6 +18,30 @@ pub type Color = (Red, Green, Blue, Alpha);
 pub type ForegroundColor = Color;
 /// Only for background
 pub type BackgroundColor = Color;
+/// Variant of a color for the dark [ColorScheme]
+///
+/// `None` uses the light color in both schemes
+pub type DarkColor = Option<Color>;
+
+/// Color scheme of the output
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorScheme {
+    /// Dark content on a light background
+    #[default]
+    Light,
+    /// Light content on a dark background
+    Dark,
+}
+
+impl ColorScheme {
+    /// Pick the color of the (light, dark) pair for this scheme
+    pub fn pick(&self, light: Color, dark: DarkColor) -> Color {
+        match self {
+            ColorScheme::Light => light,
+            ColorScheme::Dark => dark.unwrap_or(light),
+        }
+    }
+}
 
 /// Choose svg color
 #[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -30,7 +54,7 @@ pub enum SvgColor {
 impl Display for SvgColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SvgColor::None => write!(f, "None"),
+            SvgColor::None => write!(f, "none"),
             SvgColor::CurrentColor => write!(f, "currentColor"),
             SvgColor::Color(color) => write!(f, "{}", color),
         }
@@ -2,7 +2,7 @@
 
 use std::fmt::Display;
 
-use super::{Show, Size};
+use super::{Color, Show, Size};
 
 // Font
 /// Font settings
@@ -15,9 +15,73 @@ pub type FontStyle = (
     FontFamily,
 );
 
+/// Name of a font family, e.g. `Inter`
 pub type ApplyableFont = String;
 
-pub type FontFamily = (ApplyableFont, DefaultFontFamily);
+/// Font family stack
+/// - ordered fallback list of [ApplyableFont], the first installed one is used
+/// - [DefaultFontFamily] used when none of them is installed
+pub type FontFamily = (Vec<ApplyableFont>, DefaultFontFamily);
+
+/// Single entry of a [FontFamily] stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FontName<'a> {
+    /// Named font family
+    Named(&'a str),
+    /// Generic font family
+    Generic(DefaultFontFamily),
+}
+
+impl Display for FontName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontName::Named(name) => write!(f, "'{}'", escape_css_string(name)),
+            FontName::Generic(generic) => write!(f, "{}", generic),
+        }
+    }
+}
+
+/// Escapes the content of a quoted CSS string (e.g. a font name or an `url('')`),
+/// so it stays valid in `style` attributes and `<style>`.
+pub(crate) fn escape_css_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for x in value.chars() {
+        match x {
+            '\'' | '"' | '\\' | '<' | '>' | '&' => out.push_str(&format!("\\{:x} ", x as u32)),
+            x if x.is_control() => out.push_str(&format!("\\{:x} ", x as u32)),
+            x => out.push(x),
+        }
+    }
+    out
+}
+
+/// Walks the [FontFamily] stack in order of preference
+///
+/// Empty names are skipped, the generic family always goes last.
+pub fn font_stack(family: &FontFamily) -> impl Iterator<Item = FontName<'_>> {
+    let (names, generic) = family;
+    names
+        .iter()
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(FontName::Named)
+        .chain(std::iter::once(FontName::Generic(*generic)))
+}
+
+/// Finds the first named font of the [FontFamily] stack for which `is_installed` returns `true`
+///
+/// Falls back to the generic family, for renderers which resolve fonts themselves.
+pub fn resolve_font<'a>(
+    family: &'a FontFamily,
+    mut is_installed: impl FnMut(&str) -> bool,
+) -> FontName<'a> {
+    font_stack(family)
+        .find(|x| match x {
+            FontName::Named(name) => is_installed(name),
+            FontName::Generic(_) => true,
+        })
+        .unwrap_or(FontName::Generic(family.1))
+}
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DefaultFontFamily {
@@ -46,6 +110,78 @@ impl Display for DefaultFontFamily {
     }
 }
 
+// Typography
+/// Typography settings
+/// - [LineHeight]
+/// - [LetterSpacing]
+/// - [WordSpacing]
+/// - [TextAlign]
+/// - [TextTransform]
+pub type Typography = (
+    LineHeight,
+    LetterSpacing,
+    WordSpacing,
+    Option<TextAlign>,
+    TextTransform,
+);
+
+/// Height of a line relative to the font size
+///
+/// `None` inherits the value of the parent
+pub type LineHeight = Option<f64>;
+/// Extra space between letters (in px), can be negative
+pub type LetterSpacing = Option<f64>;
+/// Extra space between words (in px), can be negative
+pub type WordSpacing = Option<f64>;
+
+/// Horizontal alignment of lines inside the text block
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+impl Display for TextAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let align = match self {
+            TextAlign::Left => "left",
+            TextAlign::Center => "center",
+            TextAlign::Right => "right",
+            TextAlign::Justify => "justify",
+        };
+        write!(f, "{}", align)
+    }
+}
+
+/// Case transformation of the text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextTransform {
+    /// Text is kept as is
+    #[default]
+    None,
+    /// ALL LETTERS IN UPPERCASE
+    Uppercase,
+    /// all letters in lowercase
+    Lowercase,
+    /// First Letter Of Every Word In Uppercase
+    Capitalize,
+}
+
+impl Display for TextTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let transform = match self {
+            TextTransform::None => "none",
+            TextTransform::Uppercase => "uppercase",
+            TextTransform::Lowercase => "lowercase",
+            TextTransform::Capitalize => "capitalize",
+        };
+        write!(f, "{}", transform)
+    }
+}
+
 /// Is ~~Strikethrough~~
 pub type IsStrikethrough = Show;
 /// Is <u>Подчёркнутый текст</u>
@@ -53,47 +189,215 @@ pub type IsUnderline = Show;
 /// Is _Iteclic_
 pub type IsItalic = Show;
 
+// Decoration
+/// Decoration settings of the lines drawn by [IsUnderline] and [IsStrikethrough]
+/// - [DecorationColor]
+/// - [DecorationThickness]
+/// - [DecorationStyle]
+pub type Decoration = (DecorationColor, DecorationThickness, DecorationStyle);
+/// Color of the decoration lines
+///
+/// `None` uses the foreground color of the text
+pub type DecorationColor = Option<Color>;
+/// Thickness of the decoration lines (in px)
+///
+/// `None` uses the thickness provided by the font
+pub type DecorationThickness = Option<Size>;
+
+/// Style of the decoration lines
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Weight {
-    Heavy,
-    ExtraBold,
-    Bold,
-    SemiBold,
-    Medium,
+pub enum DecorationStyle {
+    /// ───
     #[default]
-    Normal,
-    Light,
-    ExtraLight,
-    Thin,
-}
-impl From<u64> for Weight {
-    fn from(value: u64) -> Self {
-        match value {
-            900 => Self::Heavy,
-            800 => Self::ExtraBold,
-            700 => Self::Bold,
-            600 => Self::SemiBold,
-            500 => Self::Medium,
-            400 => Self::Normal,
-            300 => Self::Light,
-            200 => Self::ExtraLight,
-            100 => Self::Thin,
-            _ => panic!("Unsoported value"),
+    Solid,
+    /// ═══
+    Double,
+    /// - - -
+    Dashed,
+    /// · · ·
+    Dotted,
+    /// ∿∿∿
+    Wavy,
+}
+
+impl Display for DecorationStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let style = match self {
+            DecorationStyle::Solid => "solid",
+            DecorationStyle::Double => "double",
+            DecorationStyle::Dashed => "dashed",
+            DecorationStyle::Dotted => "dotted",
+            DecorationStyle::Wavy => "wavy",
+        };
+        write!(f, "{}", style)
+    }
+}
+
+/// Font weight in range `1..=1000`
+///
+/// Common weights are available as named constants, e.g. [Weight::Bold],
+/// any other value of a variable font can be created with [Weight::new]
+/// or [`TryFrom<u64>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weight(u16);
+
+#[allow(non_upper_case_globals)]
+impl Weight {
+    /// 900
+    pub const Heavy: Weight = Weight(900);
+    /// 800
+    pub const ExtraBold: Weight = Weight(800);
+    /// 700
+    pub const Bold: Weight = Weight(700);
+    /// 600
+    pub const SemiBold: Weight = Weight(600);
+    /// 500
+    pub const Medium: Weight = Weight(500);
+    /// 400
+    pub const Normal: Weight = Weight(400);
+    /// 300
+    pub const Light: Weight = Weight(300);
+    /// 200
+    pub const ExtraLight: Weight = Weight(200);
+    /// 100
+    pub const Thin: Weight = Weight(100);
+
+    /// Smallest supported weight
+    pub const MIN: u16 = 1;
+    /// Largest supported weight
+    pub const MAX: u16 = 1000;
+
+    /// Returns weight if `value` is in range `1..=1000`
+    pub const fn new(value: u16) -> Option<Self> {
+        if value >= Self::MIN && value <= Self::MAX {
+            Some(Self(value))
+        } else {
+            None
         }
     }
+
+    /// Numeric value of the weight
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for Weight {
+    fn default() -> Self {
+        Self::Normal
+    }
 }
+
+impl Display for Weight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error of converting a number into [Weight]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightError {
+    /// Value is outside of `1..=1000`
+    OutOfRange(u64),
+}
+
+impl Display for WeightError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeightError::OutOfRange(value) => write!(
+                f,
+                "font weight {value} is out of range {}..={}",
+                Weight::MIN,
+                Weight::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+impl TryFrom<u64> for Weight {
+    type Error = WeightError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        u16::try_from(value)
+            .ok()
+            .and_then(Weight::new)
+            .ok_or(WeightError::OutOfRange(value))
+    }
+}
+
 impl From<Weight> for u64 {
     fn from(val: Weight) -> Self {
-        match val {
-            Weight::Heavy => 900,
-            Weight::ExtraBold => 800,
-            Weight::Bold => 700,
-            Weight::SemiBold => 600,
-            Weight::Medium => 500,
-            Weight::Normal => 400,
-            Weight::Light => 300,
-            Weight::ExtraLight => 200,
-            Weight::Thin => 100,
-        }
+        val.0 as u64
+    }
+}
+
+#[cfg(test)]
+mod test_font {
+    use super::{font_stack, resolve_font, DefaultFontFamily, FontName, Weight, WeightError};
+
+    #[test]
+    fn stack_skips_empty() {
+        let family = (
+            vec!["Inter".to_string(), " ".to_string(), "Roboto".to_string()],
+            DefaultFontFamily::Serif,
+        );
+        let stack: Vec<_> = font_stack(&family).collect();
+        assert_eq!(
+            stack,
+            vec![
+                FontName::Named("Inter"),
+                FontName::Named("Roboto"),
+                FontName::Generic(DefaultFontFamily::Serif)
+            ]
+        );
+    }
+
+    #[test]
+    fn named_is_escaped() {
+        assert_eq!(FontName::Named("Inter").to_string(), "'Inter'");
+        assert_eq!(
+            FontName::Named(r#"It's "x"</style>"#).to_string(),
+            r"'It\27 s \22 x\22 \3c /style\3e '"
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let family = (
+            vec!["Inter".to_string(), "Roboto".to_string()],
+            DefaultFontFamily::Monospace,
+        );
+        assert_eq!(
+            resolve_font(&family, |x| x == "Roboto"),
+            FontName::Named("Roboto")
+        );
+        assert_eq!(
+            resolve_font(&family, |_| false),
+            FontName::Generic(DefaultFontFamily::Monospace)
+        );
+    }
+
+    #[test]
+    fn weight_try_from() {
+        assert_eq!(Weight::try_from(700), Ok(Weight::Bold));
+        assert_eq!(Weight::try_from(450).map(Weight::get), Ok(450));
+        assert_eq!(Weight::try_from(1), Ok(Weight::new(1).unwrap()));
+        assert_eq!(Weight::try_from(1000).map(u64::from), Ok(1000));
+        assert_eq!(Weight::try_from(0), Err(WeightError::OutOfRange(0)));
+        assert_eq!(Weight::try_from(1001), Err(WeightError::OutOfRange(1001)));
+        assert_eq!(
+            Weight::try_from(u64::MAX),
+            Err(WeightError::OutOfRange(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn weight_presets() {
+        assert_eq!(Weight::default(), Weight::Normal);
+        assert_eq!(Weight::Heavy.get(), 900);
+        assert_eq!(Weight::Thin.to_string(), "100");
+        assert!(Weight::Bold > Weight::Medium);
     }
 }
@@ -0,0 +1,183 @@
+//! Font face aspects types
+//!
+//! Describes font files which renderers have to load before the text can use
+//! the family name via [crate::components::Text::font_custom].
+
+use std::{fmt::Display, io, path::Path};
+
+use super::Weight;
+
+/// Format of a font file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FontFormat {
+    Ttf,
+    Otf,
+    Woff,
+    Woff2,
+}
+
+impl FontFormat {
+    /// Guess the format by the file extension of a path or url
+    pub fn from_extension(path: &str) -> Option<Self> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let (_, extension) = path.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "ttf" => Some(Self::Ttf),
+            "otf" => Some(Self::Otf),
+            "woff" => Some(Self::Woff),
+            "woff2" => Some(Self::Woff2),
+            _ => None,
+        }
+    }
+
+    /// Mime type of the format
+    pub fn mime(&self) -> &'static str {
+        match self {
+            FontFormat::Ttf => "font/ttf",
+            FontFormat::Otf => "font/otf",
+            FontFormat::Woff => "font/woff",
+            FontFormat::Woff2 => "font/woff2",
+        }
+    }
+}
+
+impl Display for FontFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self {
+            FontFormat::Ttf => "truetype",
+            FontFormat::Otf => "opentype",
+            FontFormat::Woff => "woff",
+            FontFormat::Woff2 => "woff2",
+        };
+        write!(f, "{}", format)
+    }
+}
+
+/// Where the font file comes from
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FontSource {
+    /// Font file is loaded by url (absolute or relative to the output)
+    Url(String),
+    /// Font file is embedded into the output
+    Data(Vec<u8>),
+}
+
+/// Returns a [FontFace] which loads `family` by url
+#[inline]
+pub fn font_url(family: impl ToString, url: impl ToString) -> FontFace {
+    FontFace::url(family, url)
+}
+
+/// Returns a [FontFace] which embeds `family` from a local file
+#[inline]
+pub fn font_file(family: impl ToString, path: impl AsRef<Path>) -> io::Result<FontFace> {
+    FontFace::file(family, path)
+}
+
+/// Font file bound to a family name
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FontFace {
+    /// Family name used by [crate::components::Text::font_custom]
+    family: String,
+    /// Source of the font file
+    source: FontSource,
+    /// Format of the font file
+    format: Option<FontFormat>,
+    /// Range of weights provided by the file
+    weight: Option<(Weight, Weight)>,
+    /// Whether the file provides italic glyphs
+    is_italic: bool,
+}
+
+impl FontFace {
+    /// Create a font face loaded by url, format is guessed by extension
+    pub fn url(family: impl ToString, url: impl ToString) -> Self {
+        let url = url.to_string();
+        Self {
+            family: family.to_string(),
+            format: FontFormat::from_extension(&url),
+            source: FontSource::Url(url),
+            weight: None,
+            is_italic: false,
+        }
+    }
+
+    /// Create a font face embedded from a local file, format is guessed by extension
+    ///
+    /// The file is read at once, so the output works offline
+    pub fn file(family: impl ToString, path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let format = FontFormat::from_extension(&path.to_string_lossy());
+        Ok(Self::data(family, data, format))
+    }
+
+    /// Create a font face embedded from raw bytes
+    pub fn data(family: impl ToString, data: Vec<u8>, format: Option<FontFormat>) -> Self {
+        Self {
+            family: family.to_string(),
+            source: FontSource::Data(data),
+            format,
+            weight: None,
+            is_italic: false,
+        }
+    }
+
+    /// Set the format of the font file
+    #[inline]
+    pub fn format(mut self, format: FontFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Set the single weight provided by the file
+    #[inline]
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some((weight, weight));
+        self
+    }
+
+    /// Set the range of weights provided by a variable font file
+    #[inline]
+    pub fn weight_range(mut self, min: Weight, max: Weight) -> Self {
+        self.weight = Some((min, max));
+        self
+    }
+
+    /// Toggle whether the file provides italic glyphs
+    #[inline]
+    pub fn is_italic(mut self) -> Self {
+        self.is_italic = !self.is_italic;
+        self
+    }
+
+    /// Get the family name
+    #[inline]
+    pub fn get_family(&self) -> &str {
+        &self.family
+    }
+
+    /// Get the source of the font file
+    #[inline]
+    pub fn get_source(&self) -> &FontSource {
+        &self.source
+    }
+
+    /// Get the format of the font file
+    #[inline]
+    pub fn get_format(&self) -> Option<FontFormat> {
+        self.format
+    }
+
+    /// Get the range of weights provided by the file
+    #[inline]
+    pub fn get_weight(&self) -> Option<(Weight, Weight)> {
+        self.weight
+    }
+
+    /// Get whether the file provides italic glyphs
+    #[inline]
+    pub fn get_italic(&self) -> bool {
+        self.is_italic
+    }
+}
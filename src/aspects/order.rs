@@ -14,12 +14,52 @@ pub enum Order {
     RightToLeft,
 }
 
-/// Arrangement of the self in the self container
+/// Distribution of elements along the main axis of the container
+///
+/// The main axis follows the container [Order]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Align {
+pub enum Justify {
+    /// Packed to the start of the main axis
     #[default]
-    Left,
+    Start,
+    /// Packed around the center of the main axis
     Center,
-    Right,
+    /// Packed to the end of the main axis
+    End,
+    /// First element at the start, last at the end, equal space between
     SpaceBetween,
+    /// Equal space around every element (half-size space at the edges)
+    SpaceAround,
+    /// Equal space between elements and the edges
+    SpaceEvenly,
+}
+
+/// Arrangement of elements along the cross axis of the container
+///
+/// The cross axis is perpendicular to the container [Order]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Align {
+    /// Placed at the start of the cross axis
+    Start,
+    /// Centered on the cross axis
+    Center,
+    /// Placed at the end of the cross axis
+    End,
+    /// Stretched to fill the cross axis
+    #[default]
+    Stretch,
+    /// Aligned by the text baseline
+    Baseline,
+}
+
+/// Wrapping of elements in the container when they overflow the main axis
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Wrap {
+    /// Keep all elements on a single line
+    #[default]
+    NoWrap,
+    /// Move overflowing elements onto the next line
+    Wrap,
+    /// Move overflowing elements onto the previous line
+    WrapReverse,
 }
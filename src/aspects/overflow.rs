@@ -0,0 +1,26 @@
+//! Overflow aspects types
+
+/// Behaviour of content which does not fit into the element
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Overflow {
+    /// Content is drawn outside of the element
+    #[default]
+    Visible,
+    /// Content is clipped by the element edges
+    Clip,
+    /// Content is clipped by the element edges and padding box
+    Hidden,
+}
+
+/// Truncation of text which does not fit into the element
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Truncate {
+    /// Text wraps freely
+    #[default]
+    None,
+    /// Text is kept on a single line and cut with `…`
+    Ellipsis,
+    /// Text is clamped to the given number of lines and cut with `…`,
+    /// `Lines(0)` wraps freely like [Truncate::None]
+    Lines(u64),
+}
@@ -0,0 +1,58 @@
+//! Semantic roles of components
+//!
+//! Roles describe the meaning of an element for accessibility and SEO,
+//! layouts map them onto their own elements (e.g. HTML tags).
+
+/// Semantic role of a text
+///
+/// div | h1 | h2 | h3 | h4 | h5 | h6 | p | span | strong | code | blockquote
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TextRole {
+    /// Generic block without meaning
+    #[default]
+    Generic,
+    /// Heading of the 1st (top) level
+    H1,
+    /// Heading of the 2nd level
+    H2,
+    /// Heading of the 3rd level
+    H3,
+    /// Heading of the 4th level
+    H4,
+    /// Heading of the 5th level
+    H5,
+    /// Heading of the 6th level
+    H6,
+    /// Paragraph
+    Paragraph,
+    /// Generic inline text
+    Span,
+    /// Important text
+    Strong,
+    /// Fragment of code
+    Code,
+    /// Quotation
+    Blockquote,
+}
+
+/// Semantic role of a container
+///
+/// div | section | article | header | footer | nav | figure
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ContainerRole {
+    /// Generic block without meaning
+    #[default]
+    Generic,
+    /// Thematic grouping of content
+    Section,
+    /// Self-contained composition, e.g. a card or a post
+    Article,
+    /// Introductory content
+    Header,
+    /// Closing content
+    Footer,
+    /// Navigation links
+    Nav,
+    /// Self-contained content with an optional caption, e.g. an image
+    Figure,
+}
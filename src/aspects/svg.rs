@@ -2,6 +2,8 @@
 
 use std::fmt::Display;
 
+use super::{Path, SvgColor};
+
 /// The stroke-linejoin attribute is a presentation attribute defining the shape to be used at the corners of paths when they are stroked.
 ///
 /// [MDM](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linejoin)
@@ -50,3 +52,304 @@ impl Display for StrokeLinecap {
         }
     }
 }
+
+/// The fill-rule attribute is a presentation attribute defining the algorithm to use to determine the inside part of a shape.
+///
+/// [MDM](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule)
+///
+/// nonzero | evenodd
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FillRule {
+    #[default]
+    NonZero,
+    EvenOdd,
+}
+
+impl Display for FillRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FillRule::NonZero => write!(f, "nonzero"),
+            FillRule::EvenOdd => write!(f, "evenodd"),
+        }
+    }
+}
+
+/// Position and size of the SVG viewport in user space: min-x, min-y, width, height
+pub type ViewBox = (f64, f64, f64, f64);
+
+/// The preserveAspectRatio attribute indicates how an element with a viewBox must be fitted into a viewport with a different aspect ratio.
+///
+/// [MDM](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio)
+pub type PreserveAspectRatio = (AspectRatioAlign, MeetOrSlice);
+
+/// Alignment of the viewBox inside of the viewport
+///
+/// none | xMinYMin | xMidYMin | xMaxYMin | xMinYMid | xMidYMid | xMaxYMid | xMinYMax | xMidYMax | xMaxYMax
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AspectRatioAlign {
+    /// Stretches the viewBox to the viewport
+    None,
+    XMinYMin,
+    XMidYMin,
+    XMaxYMin,
+    XMinYMid,
+    #[default]
+    XMidYMid,
+    XMaxYMid,
+    XMinYMax,
+    XMidYMax,
+    XMaxYMax,
+}
+
+impl Display for AspectRatioAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AspectRatioAlign::None => write!(f, "none"),
+            AspectRatioAlign::XMinYMin => write!(f, "xMinYMin"),
+            AspectRatioAlign::XMidYMin => write!(f, "xMidYMin"),
+            AspectRatioAlign::XMaxYMin => write!(f, "xMaxYMin"),
+            AspectRatioAlign::XMinYMid => write!(f, "xMinYMid"),
+            AspectRatioAlign::XMidYMid => write!(f, "xMidYMid"),
+            AspectRatioAlign::XMaxYMid => write!(f, "xMaxYMid"),
+            AspectRatioAlign::XMinYMax => write!(f, "xMinYMax"),
+            AspectRatioAlign::XMidYMax => write!(f, "xMidYMax"),
+            AspectRatioAlign::XMaxYMax => write!(f, "xMaxYMax"),
+        }
+    }
+}
+
+/// Scaling of the viewBox: fit entirely (meet) or cover the viewport (slice)
+///
+/// meet | slice
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MeetOrSlice {
+    #[default]
+    Meet,
+    Slice,
+}
+
+impl Display for MeetOrSlice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeetOrSlice::Meet => write!(f, "meet"),
+            MeetOrSlice::Slice => write!(f, "slice"),
+        }
+    }
+}
+
+/// Geometry of a [SvgElement]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum SvgShape {
+    /// `<path d="..."/>`
+    Path(Path),
+    /// `<circle cx cy r/>`
+    Circle { cx: f64, cy: f64, r: f64 },
+    /// `<rect x y width height rx ry/>`
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        rx: f64,
+        ry: f64,
+    },
+    /// `<line x1 y1 x2 y2/>`
+    Line { x1: f64, y1: f64, x2: f64, y2: f64 },
+    /// `<g>...</g>`
+    Group(Vec<SvgElement>),
+}
+
+/// Returns [SvgElement] with path data
+#[inline]
+pub fn svg_path(d: impl ToString) -> SvgElement {
+    SvgElement::new(SvgShape::Path(d.to_string()))
+}
+
+/// SVG primitive with optional paint, unset paint is inherited from the parent
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct SvgElement {
+    /// Geometry of the element
+    shape: SvgShape,
+    /// Fill color
+    fill: Option<SvgColor>,
+    /// Stroke color
+    stroke: Option<SvgColor>,
+    /// Width of the stroke lines
+    stroke_width: Option<f64>,
+    /// Opacity in range `0.0..=1.0`
+    opacity: Option<f64>,
+    /// Algorithm of the inside part of the shape
+    fill_rule: Option<FillRule>,
+    /// Transform list, e.g. `translate(2 2) scale(0.5)`
+    transform: Option<String>,
+}
+
+impl SvgElement {
+    /// Create an element without paint
+    pub fn new(shape: SvgShape) -> Self {
+        Self {
+            shape,
+            fill: None,
+            stroke: None,
+            stroke_width: None,
+            opacity: None,
+            fill_rule: None,
+            transform: None,
+        }
+    }
+
+    /// Create a path
+    #[inline]
+    pub fn path(d: impl ToString) -> Self {
+        svg_path(d)
+    }
+
+    /// Create a circle
+    #[inline]
+    pub fn circle(cx: f64, cy: f64, r: f64) -> Self {
+        Self::new(SvgShape::Circle { cx, cy, r })
+    }
+
+    /// Create a rectangle
+    #[inline]
+    pub fn rect(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self::rounded_rect(x, y, width, height, 0.0, 0.0)
+    }
+
+    /// Create a rectangle with rounded corners
+    #[inline]
+    pub fn rounded_rect(x: f64, y: f64, width: f64, height: f64, rx: f64, ry: f64) -> Self {
+        Self::new(SvgShape::Rect {
+            x,
+            y,
+            width,
+            height,
+            rx,
+            ry,
+        })
+    }
+
+    /// Create a line
+    #[inline]
+    pub fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self::new(SvgShape::Line { x1, y1, x2, y2 })
+    }
+
+    /// Create a group of elements
+    #[inline]
+    pub fn group(children: impl IntoIterator<Item = SvgElement>) -> Self {
+        Self::new(SvgShape::Group(children.into_iter().collect()))
+    }
+
+    /// Set the fill color
+    #[inline]
+    pub fn fill(mut self, fill: impl ToString) -> Self {
+        self.fill = Some(SvgColor::Color(fill.to_string()));
+        self
+    }
+
+    /// Remove the fill
+    #[inline]
+    pub fn fill_none(mut self) -> Self {
+        self.fill = Some(SvgColor::None);
+        self
+    }
+
+    /// Set the fill color to `currentColor`
+    #[inline]
+    pub fn fill_current_color(mut self) -> Self {
+        self.fill = Some(SvgColor::CurrentColor);
+        self
+    }
+
+    /// Set the stroke color
+    #[inline]
+    pub fn stroke(mut self, stroke: impl ToString) -> Self {
+        self.stroke = Some(SvgColor::Color(stroke.to_string()));
+        self
+    }
+
+    /// Remove the stroke
+    #[inline]
+    pub fn stroke_none(mut self) -> Self {
+        self.stroke = Some(SvgColor::None);
+        self
+    }
+
+    /// Set the stroke color to `currentColor`
+    #[inline]
+    pub fn stroke_current_color(mut self) -> Self {
+        self.stroke = Some(SvgColor::CurrentColor);
+        self
+    }
+
+    /// Set the width of the stroke lines
+    #[inline]
+    pub fn stroke_width(mut self, stroke_width: f64) -> Self {
+        self.stroke_width = Some(stroke_width);
+        self
+    }
+
+    /// Set the opacity
+    #[inline]
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Set the fill rule
+    #[inline]
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    /// Set the transform list
+    #[inline]
+    pub fn transform(mut self, transform: impl ToString) -> Self {
+        self.transform = Some(transform.to_string());
+        self
+    }
+
+    /// Get the geometry
+    #[inline]
+    pub fn get_shape(&self) -> &SvgShape {
+        &self.shape
+    }
+
+    /// Get the fill color
+    #[inline]
+    pub fn get_fill(&self) -> Option<&SvgColor> {
+        self.fill.as_ref()
+    }
+
+    /// Get the stroke color
+    #[inline]
+    pub fn get_stroke(&self) -> Option<&SvgColor> {
+        self.stroke.as_ref()
+    }
+
+    /// Get the width of the stroke lines
+    #[inline]
+    pub fn get_stroke_width(&self) -> Option<f64> {
+        self.stroke_width
+    }
+
+    /// Get the opacity
+    #[inline]
+    pub fn get_opacity(&self) -> Option<f64> {
+        self.opacity
+    }
+
+    /// Get the fill rule
+    #[inline]
+    pub fn get_fill_rule(&self) -> Option<FillRule> {
+        self.fill_rule
+    }
+
+    /// Get the transform list
+    #[inline]
+    pub fn get_transform(&self) -> Option<&str> {
+        self.transform.as_deref()
+    }
+}
@@ -0,0 +1,214 @@
+//! Design token aspects types
+//!
+//! Tokens reference values of a [crate::theme::Theme] instead of raw values,
+//! so switching the theme restyles every component which uses them.
+
+use std::fmt::Display;
+
+/// Color of the theme palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorToken {
+    /// Page background
+    Background,
+    /// Background of cards and other raised elements
+    Surface,
+    /// Main text color
+    Foreground,
+    /// Secondary text color
+    Muted,
+    /// Brand color
+    Primary,
+    /// Highlight color
+    Accent,
+    /// Border and divider color
+    Border,
+}
+
+impl ColorToken {
+    /// Every color token
+    pub const ALL: [ColorToken; 7] = [
+        ColorToken::Background,
+        ColorToken::Surface,
+        ColorToken::Foreground,
+        ColorToken::Muted,
+        ColorToken::Primary,
+        ColorToken::Accent,
+        ColorToken::Border,
+    ];
+}
+
+impl Display for ColorToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ColorToken::Background => "background",
+            ColorToken::Surface => "surface",
+            ColorToken::Foreground => "foreground",
+            ColorToken::Muted => "muted",
+            ColorToken::Primary => "primary",
+            ColorToken::Accent => "accent",
+            ColorToken::Border => "border",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Step of the theme spacing scale (gap, padding)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpaceToken {
+    None,
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xl2,
+}
+
+impl SpaceToken {
+    /// Every space token
+    pub const ALL: [SpaceToken; 7] = [
+        SpaceToken::None,
+        SpaceToken::Xs,
+        SpaceToken::Sm,
+        SpaceToken::Md,
+        SpaceToken::Lg,
+        SpaceToken::Xl,
+        SpaceToken::Xl2,
+    ];
+}
+
+impl Display for SpaceToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SpaceToken::None => "none",
+            SpaceToken::Xs => "xs",
+            SpaceToken::Sm => "sm",
+            SpaceToken::Md => "md",
+            SpaceToken::Lg => "lg",
+            SpaceToken::Xl => "xl",
+            SpaceToken::Xl2 => "2xl",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Step of the theme border radius scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RadiusToken {
+    None,
+    Sm,
+    Md,
+    Lg,
+    Full,
+}
+
+impl RadiusToken {
+    /// Every radius token
+    pub const ALL: [RadiusToken; 5] = [
+        RadiusToken::None,
+        RadiusToken::Sm,
+        RadiusToken::Md,
+        RadiusToken::Lg,
+        RadiusToken::Full,
+    ];
+}
+
+impl Display for RadiusToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            RadiusToken::None => "none",
+            RadiusToken::Sm => "sm",
+            RadiusToken::Md => "md",
+            RadiusToken::Lg => "lg",
+            RadiusToken::Full => "full",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Step of the theme type scale
+///
+/// [Tailwindcss font size](https://tailwindcss.com/docs/font-size)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontSizeToken {
+    Xs,
+    Sm,
+    Base,
+    Lg,
+    Xl,
+    Xl2,
+    Xl3,
+    Xl4,
+    Xl5,
+    Xl6,
+    Xl7,
+    Xl8,
+    Xl9,
+}
+
+impl FontSizeToken {
+    /// Every font size token
+    pub const ALL: [FontSizeToken; 13] = [
+        FontSizeToken::Xs,
+        FontSizeToken::Sm,
+        FontSizeToken::Base,
+        FontSizeToken::Lg,
+        FontSizeToken::Xl,
+        FontSizeToken::Xl2,
+        FontSizeToken::Xl3,
+        FontSizeToken::Xl4,
+        FontSizeToken::Xl5,
+        FontSizeToken::Xl6,
+        FontSizeToken::Xl7,
+        FontSizeToken::Xl8,
+        FontSizeToken::Xl9,
+    ];
+}
+
+impl Display for FontSizeToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            FontSizeToken::Xs => "xs",
+            FontSizeToken::Sm => "sm",
+            FontSizeToken::Base => "base",
+            FontSizeToken::Lg => "lg",
+            FontSizeToken::Xl => "xl",
+            FontSizeToken::Xl2 => "2xl",
+            FontSizeToken::Xl3 => "3xl",
+            FontSizeToken::Xl4 => "4xl",
+            FontSizeToken::Xl5 => "5xl",
+            FontSizeToken::Xl6 => "6xl",
+            FontSizeToken::Xl7 => "7xl",
+            FontSizeToken::Xl8 => "8xl",
+            FontSizeToken::Xl9 => "9xl",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Font family of the theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontToken {
+    /// Font of the regular text
+    Body,
+    /// Font of the headings
+    Heading,
+    /// Font of the code
+    Mono,
+}
+
+impl FontToken {
+    /// Every font token
+    pub const ALL: [FontToken; 3] = [FontToken::Body, FontToken::Heading, FontToken::Mono];
+}
+
+impl Display for FontToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            FontToken::Body => "body",
+            FontToken::Heading => "heading",
+            FontToken::Mono => "mono",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -3,13 +3,98 @@
 //! The `Components` enum acts as a unified abstraction for different UI elements such as containers, text, and icons.
 //! This module also includes conversions and a default implementation for `Components`.
 
+/// Implements the builders of [crate::aspects::attributes] for a component
+/// with `id`, `classes` and `attributes` fields.
+macro_rules! impl_attributes {
+    ($component:ident) => {
+        impl $component {
+            /// Sets the unique id of the element.
+            #[inline]
+            pub fn id(mut self, id: impl ToString) -> Self {
+                self.id = Some(id.to_string());
+                self
+            }
+
+            /// Adds a class (or several, separated by whitespace) to the element.
+            #[inline]
+            pub fn class(mut self, class: impl ToString) -> Self {
+                let class = class.to_string();
+                self.classes
+                    .extend(class.split_whitespace().map(|x| x.to_string()));
+                self
+            }
+
+            /// Adds multiple classes to the element.
+            #[inline]
+            pub fn classes(mut self, classes: impl IntoIterator<Item = impl ToString>) -> Self {
+                for class in classes {
+                    self = self.class(class);
+                }
+                self
+            }
+
+            /// Sets a `data-*` attribute, e.g. `data("repo-id", 42)` for `data-repo-id="42"`.
+            #[inline]
+            pub fn data(mut self, name: impl AsRef<str>, value: impl ToString) -> Self {
+                let name = crate::aspects::attribute_name("data", name.as_ref());
+                self.attributes.insert(name, value.to_string());
+                self
+            }
+
+            /// Sets an `aria-*` attribute, e.g. `aria("label", "Stars")` for `aria-label="Stars"`.
+            #[inline]
+            pub fn aria(mut self, name: impl AsRef<str>, value: impl ToString) -> Self {
+                let name = crate::aspects::attribute_name("aria", name.as_ref());
+                self.attributes.insert(name, value.to_string());
+                self
+            }
+
+            /// Sets the accessible name of the element (`aria-label`).
+            #[inline]
+            pub fn label(self, label: impl ToString) -> Self {
+                self.aria("label", label)
+            }
+
+            /// Retrieves the accessible name of the element (`aria-label`).
+            #[inline]
+            pub fn get_label(&self) -> Option<&str> {
+                self.attributes.get("aria-label").map(|x| x.as_str())
+            }
+
+            /// Retrieves the unique id of the element.
+            #[inline]
+            pub fn get_id(&self) -> Option<&str> {
+                self.id.as_deref()
+            }
+
+            /// Retrieves the class list of the element.
+            #[inline]
+            pub fn get_classes(&self) -> &[String] {
+                &self.classes
+            }
+
+            /// Retrieves the custom (`data-*`, `aria-*`) attributes of the element.
+            #[inline]
+            pub fn get_attributes(&self) -> &crate::aspects::Attributes {
+                &self.attributes
+            }
+        }
+    };
+}
+
 pub mod container;
+pub mod custom;
 pub mod icon;
+pub mod lint;
 pub mod text;
+pub mod visit;
 
 pub use container::*;
+pub use custom::*;
 pub use icon::*;
+pub use lint::*;
 pub use text::*;
+pub use visit::*;
 
 /// Represents different types of UI components.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -20,6 +105,20 @@ pub enum Components {
     Text(Text),
     /// Icon component.
     Icon(Icon),
+    /// User defined component, expanded at render time.
+    Custom(Custom),
+}
+
+impl Components {
+    /// Retrieves the unique id of the component, `None` for custom components.
+    pub fn get_id(&self) -> Option<&str> {
+        match self {
+            Components::Container(component) => component.get_id(),
+            Components::Text(component) => component.get_id(),
+            Components::Icon(component) => component.get_id(),
+            Components::Custom(_) => None,
+        }
+    }
 }
 
 impl From<&Components> for Components {
@@ -49,6 +148,20 @@ impl From<Icon> for Components {
     }
 }
 
+impl From<Custom> for Components {
+    /// Converts a `Custom` into a `Components` variant.
+    fn from(value: Custom) -> Self {
+        Self::Custom(value)
+    }
+}
+
+impl<T: Component> From<T> for Components {
+    /// Wraps a user defined component into a `Components::Custom` variant.
+    fn from(value: T) -> Self {
+        Self::Custom(Custom::new(value))
+    }
+}
+
 impl Default for Components {
     /// Returns a default `Components` variant, which is a `Container`.
     fn default() -> Self {
@@ -1,8 +1,9 @@
 //! Container component
 
 use crate::aspects::{
-    Align, BackgroundColor, BorderPart, BorderStyle, Color, Gap, Height, Order, Padding, Size,
-    Width,
+    Align, Attributes, BackgroundColor, BorderPart, BorderStyle, Classes, Color, ColorScheme,
+    ColorToken, ContainerRole, DarkColor, Gap, Height, Id, Justify, Order, Overflow, Padding,
+    RadiusToken, Size, SpaceToken, Width, Wrap,
 };
 
 use super::Components;
@@ -34,16 +35,52 @@ pub struct Container {
     direction: Order,
     /// Gap between components in the container.
     gap: Gap,
+    /// Gap between rows, overrides [Container::gap] for rows.
+    row_gap: Option<Gap>,
+    /// Gap between columns, overrides [Container::gap] for columns.
+    column_gap: Option<Gap>,
+    /// Wrapping of components which overflow the container.
+    wrap: Wrap,
     /// Padding inside the container.
     padding: Padding,
     /// Whether the container uses flexible layout.
     is_flex: bool,
-    /// Alignment of content within the container.
-    align_content: Align,
-    /// Alignment of individual items within the container.
+    /// Distribution of components along the main axis.
+    justify_content: Justify,
+    /// Distribution of wrapped lines along the cross axis.
+    align_content: Option<Justify>,
+    /// Alignment of components along the cross axis.
     align_items: Align,
+    /// Alignment of the container itself inside its parent, overrides parent `align_items`.
+    align_self: Option<Align>,
+    /// Semantic role of the element, e.g. a heading.
+    role: ContainerRole,
+    /// Behaviour of components which do not fit into the container.
+    overflow: Overflow,
+    /// Background color of the container in the dark color scheme.
+    background_color_dark: DarkColor,
+    /// Border color of the container in the dark color scheme.
+    border_color_dark: DarkColor,
+    /// Theme color of the background, overrides [Container::background_color] until it's set again.
+    background_token: Option<ColorToken>,
+    /// Theme color of the border, overrides [Container::border_color] until it's set again.
+    border_color_token: Option<ColorToken>,
+    /// Theme border radius, overrides [Container::border_radius] until it's set again.
+    border_radius_token: Option<RadiusToken>,
+    /// Theme gap, overrides [Container::gap] until it's set again.
+    gap_token: Option<SpaceToken>,
+    /// Theme padding, overrides [Container::padding] until it's set again.
+    padding_token: Option<SpaceToken>,
+    /// Unique id of the element.
+    id: Id,
+    /// Class list of the element.
+    classes: Classes,
+    /// Custom (`data-*`, `aria-*`) attributes of the element.
+    attributes: Attributes,
 }
 
+impl_attributes!(Container);
+
 impl Container {
     /// add component to end of list
     #[inline]
@@ -76,10 +113,41 @@ impl Container {
         &self.components
     }
 
+    /// Retrieves all components in the container for modification.
+    #[inline]
+    pub fn get_components_mut(&mut self) -> &mut Vec<Components> {
+        &mut self.components
+    }
+
     /// Sets the gap between components in the container.
     #[inline]
     pub fn gap(mut self, gap: Gap) -> Self {
         self.gap = gap;
+        self.gap_token = None;
+        self
+    }
+
+    /// Sets the gap between rows of the container.
+    #[inline]
+    pub fn row_gap(mut self, row_gap: Gap) -> Self {
+        self.row_gap = Some(row_gap);
+        self
+    }
+
+    /// Sets the gap between columns of the container.
+    #[inline]
+    pub fn column_gap(mut self, column_gap: Gap) -> Self {
+        self.column_gap = Some(column_gap);
+        self
+    }
+
+    /// Sets the wrapping of components in the container.
+    /// - [Wrap::NoWrap] | Default
+    /// - [Wrap::Wrap]
+    /// - [Wrap::WrapReverse]
+    #[inline]
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
         self
     }
 
@@ -87,6 +155,7 @@ impl Container {
     #[inline]
     pub fn padding(mut self, padding: Padding) -> Self {
         self.padding = padding;
+        self.padding_token = None;
         self
     }
 
@@ -96,6 +165,24 @@ impl Container {
         &self.gap
     }
 
+    /// Retrieves the row gap setting of the container.
+    #[inline]
+    pub fn get_row_gap(&self) -> Option<Gap> {
+        self.row_gap
+    }
+
+    /// Retrieves the column gap setting of the container.
+    #[inline]
+    pub fn get_column_gap(&self) -> Option<Gap> {
+        self.column_gap
+    }
+
+    /// Retrieves the wrapping setting of the container.
+    #[inline]
+    pub fn get_wrap(&self) -> &Wrap {
+        &self.wrap
+    }
+
     /// Retrieves the padding setting of the container.
     #[inline]
     pub fn get_padding(&self) -> &Padding {
@@ -106,6 +193,7 @@ impl Container {
     #[inline]
     pub fn background_color(mut self, background_color: BackgroundColor) -> Self {
         self.background_color = background_color;
+        self.background_token = None;
         self
     }
 
@@ -141,6 +229,8 @@ impl Container {
     #[inline]
     pub fn border(mut self, border: BorderStyle) -> Self {
         self.border = border;
+        self.border_color_token = None;
+        self.border_radius_token = None;
         self
     }
 
@@ -148,6 +238,7 @@ impl Container {
     #[inline]
     pub fn border_color(mut self, border_color: Color) -> Self {
         self.border.1 = border_color;
+        self.border_color_token = None;
         self
     }
 
@@ -155,6 +246,7 @@ impl Container {
     #[inline]
     pub fn border_radius(mut self, border_size: Size) -> Self {
         self.border.2 = border_size;
+        self.border_radius_token = None;
         self
     }
 
@@ -223,20 +315,115 @@ impl Container {
         self
     }
 
-    /// Sets the alignment of content within the container.
+    /// Sets the distribution of components along the main axis.
+    /// - [Justify::Start] | Default
+    /// - [Justify::Center]
+    /// - [Justify::End]
+    /// - [Justify::SpaceBetween]
+    /// - [Justify::SpaceAround]
+    /// - [Justify::SpaceEvenly]
+    #[inline]
+    pub fn justify_content(mut self, justify_content: Justify) -> Self {
+        self.justify_content = justify_content;
+        self
+    }
+
+    /// Sets the distribution of wrapped lines along the cross axis.
+    ///
+    /// **note** has effect only with [Container::wrap]
     #[inline]
-    pub fn align_content(mut self, align_content: Align) -> Self {
-        self.align_content = align_content;
+    pub fn align_content(mut self, align_content: Justify) -> Self {
+        self.align_content = Some(align_content);
         self
     }
 
-    /// Sets the alignment of individual items within the container.
+    /// Sets the alignment of components along the cross axis.
+    /// - [Align::Start]
+    /// - [Align::Center]
+    /// - [Align::End]
+    /// - [Align::Stretch] | Default
+    /// - [Align::Baseline]
     #[inline]
     pub fn align_items(mut self, align_items: Align) -> Self {
         self.align_items = align_items;
         self
     }
 
+    /// Sets the alignment of the container inside its parent.
+    #[inline]
+    pub fn align_self(mut self, align_self: Align) -> Self {
+        self.align_self = Some(align_self);
+        self
+    }
+
+    /// Sets the semantic role of the element, e.g. [ContainerRole::Article].
+    #[inline]
+    pub fn role(mut self, role: ContainerRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Sets the behaviour of components which do not fit into the container.
+    /// - [Overflow::Visible] | Default
+    /// - [Overflow::Clip]
+    /// - [Overflow::Hidden]
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the background color of the container in the dark color scheme.
+    #[inline]
+    pub fn background_color_dark(mut self, background_color_dark: BackgroundColor) -> Self {
+        self.background_color_dark = Some(background_color_dark);
+        self
+    }
+
+    /// Sets the border color of the container in the dark color scheme.
+    #[inline]
+    pub fn border_color_dark(mut self, border_color_dark: Color) -> Self {
+        self.border_color_dark = Some(border_color_dark);
+        self
+    }
+
+    /// Sets the theme color of the background.
+    ///
+    /// The raw [Container::background_color] is used as fallback without theme.
+    #[inline]
+    pub fn background_token(mut self, token: ColorToken) -> Self {
+        self.background_token = Some(token);
+        self
+    }
+
+    /// Sets the theme color of the border.
+    #[inline]
+    pub fn border_color_token(mut self, token: ColorToken) -> Self {
+        self.border_color_token = Some(token);
+        self
+    }
+
+    /// Sets the theme border radius.
+    #[inline]
+    pub fn border_radius_token(mut self, token: RadiusToken) -> Self {
+        self.border_radius_token = Some(token);
+        self
+    }
+
+    /// Sets the theme gap between components.
+    #[inline]
+    pub fn gap_token(mut self, token: SpaceToken) -> Self {
+        self.gap_token = Some(token);
+        self
+    }
+
+    /// Sets the theme padding inside the container.
+    #[inline]
+    pub fn padding_token(mut self, token: SpaceToken) -> Self {
+        self.padding_token = Some(token);
+        self
+    }
+
     /// Retrieves the flexible layout setting of the container.
     #[inline]
     pub fn get_flex(&self) -> &bool {
@@ -255,18 +442,42 @@ impl Container {
         &self.is_height_full
     }
 
-    /// Retrieves the alignment of content within the container.
+    /// Retrieves the distribution of components along the main axis.
+    #[inline]
+    pub fn get_justify_content(&self) -> &Justify {
+        &self.justify_content
+    }
+
+    /// Retrieves the distribution of wrapped lines along the cross axis.
     #[inline]
-    pub fn get_align_content(&self) -> &Align {
-        &self.align_content
+    pub fn get_align_content(&self) -> Option<Justify> {
+        self.align_content
     }
 
-    /// Retrieves the alignment of items within the container.
+    /// Retrieves the alignment of components along the cross axis.
     #[inline]
     pub fn get_align_items(&self) -> &Align {
         &self.align_items
     }
 
+    /// Retrieves the alignment of the container inside its parent.
+    #[inline]
+    pub fn get_align_self(&self) -> Option<Align> {
+        self.align_self
+    }
+
+    /// Retrieves the semantic role of the element.
+    #[inline]
+    pub fn get_role(&self) -> ContainerRole {
+        self.role
+    }
+
+    /// Retrieves the overflow behaviour of the container.
+    #[inline]
+    pub fn get_overflow(&self) -> &Overflow {
+        &self.overflow
+    }
+
     /// Retrieves the background color of the container.
     #[inline]
     pub fn get_background_color(&self) -> &(u8, u8, u8, u8) {
@@ -296,4 +507,58 @@ impl Container {
     pub fn get_direction(&self) -> &Order {
         &self.direction
     }
+
+    /// Retrieves the theme color of the background.
+    #[inline]
+    pub fn get_background_token(&self) -> Option<ColorToken> {
+        self.background_token
+    }
+
+    /// Retrieves the theme color of the border.
+    #[inline]
+    pub fn get_border_color_token(&self) -> Option<ColorToken> {
+        self.border_color_token
+    }
+
+    /// Retrieves the theme border radius.
+    #[inline]
+    pub fn get_border_radius_token(&self) -> Option<RadiusToken> {
+        self.border_radius_token
+    }
+
+    /// Retrieves the theme gap between components.
+    #[inline]
+    pub fn get_gap_token(&self) -> Option<SpaceToken> {
+        self.gap_token
+    }
+
+    /// Retrieves the theme padding inside the container.
+    #[inline]
+    pub fn get_padding_token(&self) -> Option<SpaceToken> {
+        self.padding_token
+    }
+
+    /// Retrieves the background color of the container in the dark color scheme.
+    #[inline]
+    pub fn get_background_color_dark(&self) -> DarkColor {
+        self.background_color_dark
+    }
+
+    /// Retrieves the border color of the container in the dark color scheme.
+    #[inline]
+    pub fn get_border_color_dark(&self) -> DarkColor {
+        self.border_color_dark
+    }
+
+    /// Retrieves the background color of the container for the color scheme.
+    #[inline]
+    pub fn get_background_color_for(&self, scheme: ColorScheme) -> BackgroundColor {
+        scheme.pick(self.background_color, self.background_color_dark)
+    }
+
+    /// Retrieves the border color of the container for the color scheme.
+    #[inline]
+    pub fn get_border_color_for(&self, scheme: ColorScheme) -> Color {
+        scheme.pick(self.border.1, self.border_color_dark)
+    }
 }
@@ -0,0 +1,97 @@
+//! Custom (user defined) components
+//!
+//! A type implementing [Component] can be used anywhere `impl Into<Components>`
+//! is accepted, it's expanded with [Component::render] when it's passed to
+//! `HtmlBuilder::component` (once per tree) or traversed (see [Components::walk]).
+//!
+//! # Example
+//! ```rust
+//! use zen_rs::{
+//!     components::{text, Component, Components},
+//!     dynamic_stack::{hstack, vstack},
+//!     layouts::html::html_builder,
+//! };
+//!
+//! #[derive(Debug)]
+//! struct RepoCard {
+//!     name: String,
+//!     stars: u32,
+//! }
+//!
+//! impl Component for RepoCard {
+//!     fn render(&self) -> Components {
+//!         hstack((
+//!             text().content(&self.name),
+//!             text().content(format!("★ {}", self.stars)),
+//!         ))
+//!         .gap(8)
+//!         .into()
+//!     }
+//! }
+//!
+//! let card = RepoCard { name: "zen-rs".to_string(), stars: 42 };
+//! let html = html_builder().component(vstack((card,))).build();
+//! assert!(html.contains("★ 42"));
+//! ```
+
+use std::{fmt::Debug, sync::Arc};
+
+use super::Components;
+
+/// Reusable component built from other components.
+///
+/// Fields of the implementing type are the props of the component.
+pub trait Component: Debug + Send + Sync + 'static {
+    /// Builds the tree of the component.
+    ///
+    /// Should be cheap and pure: traversals call it again on every pass.
+    fn render(&self) -> Components;
+}
+
+/// Returns [Custom] with a user defined component.
+#[inline]
+pub fn custom(component: impl Component) -> Custom {
+    Custom::new(component)
+}
+
+/// Shared user defined [Component]
+///
+/// Two custom components are equal when they render equal trees, clones of
+/// one `Custom` are equal without rendering.
+#[derive(Debug, Clone)]
+pub struct Custom(Arc<dyn Component>);
+
+impl Custom {
+    /// Wraps a user defined component.
+    #[inline]
+    pub fn new(component: impl Component) -> Self {
+        Self(Arc::new(component))
+    }
+
+    /// Retrieves the user defined component.
+    #[inline]
+    pub fn get_component(&self) -> &dyn Component {
+        self.0.as_ref()
+    }
+
+    /// Builds the tree of the component.
+    #[inline]
+    pub fn render(&self) -> Components {
+        self.0.render()
+    }
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.render() == other.render()
+    }
+}
+
+impl PartialOrd for Custom {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if Arc::ptr_eq(&self.0, &other.0) {
+            return Some(std::cmp::Ordering::Equal);
+        }
+        self.render().partial_cmp(&other.render())
+    }
+}
@@ -1,8 +1,15 @@
 //! Icon component
 
 pub mod github;
+pub mod import;
+#[cfg(feature = "tabler")]
+pub mod tabler;
 
-use crate::aspects::{Height, Path, Size, StrokeLinecap, StrokeLinejoin, SvgColor, Width};
+use crate::aspects::{
+    svg_path, Align, AspectRatioAlign, Attributes, Classes, Color, DarkColor, Height, Id,
+    MeetOrSlice, PreserveAspectRatio, Size, StrokeLinecap, StrokeLinejoin, SvgColor, SvgElement,
+    ViewBox, Width,
+};
 
 /// Default attribute for the `xmlns` in SVG elements.
 pub static XMLNS: &str = r"http://www.w3.org/2000/svg";
@@ -16,12 +23,16 @@ pub fn icon() -> Icon {
 #[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
 /// Represents SVG settings, including content, colors, dimensions, and specific attributes.
 pub struct Icon {
-    /// Content paths of the SVG.
-    content: Vec<Path>,
+    /// Content elements of the SVG.
+    content: Vec<SvgElement>,
     /// Foreground color of the SVG.
     foreground_color: SvgColor,
     /// Background color of the SVG.
     background_color: SvgColor,
+    /// Foreground color of the SVG in the dark color scheme.
+    foreground_color_dark: DarkColor,
+    /// Background color of the SVG in the dark color scheme.
+    background_color_dark: DarkColor,
     /// Width of the SVG.
     width: Width,
     /// Height of the SVG.
@@ -33,14 +44,30 @@ pub struct Icon {
     /// Width of the stroke lines.
     stroke_width: Option<f64>,
     /// Viewbox dimensions of the SVG.
-    view_box: (u8, u8, u8, u8),
+    view_box: ViewBox,
+    /// Fitting of the viewbox into the width and height of the SVG.
+    preserve_aspect_ratio: Option<PreserveAspectRatio>,
+    /// Alignment of the SVG inside its parent container.
+    align_self: Option<Align>,
+    /// Accessible name of the SVG, e.g. HTML `<title>`.
+    title: Option<String>,
+    /// Whether the SVG is decorative and hidden from assistive technologies.
+    is_decorative: bool,
+    /// Unique id of the element.
+    id: Id,
+    /// Class list of the element.
+    classes: Classes,
+    /// Custom (`data-*`, `aria-*`) attributes of the element.
+    attributes: Attributes,
 }
 
+impl_attributes!(Icon);
+
 impl Icon {
-    /// Retrieves the content paths of the SVG.
+    /// Retrieves the content elements of the SVG.
     #[inline]
-    pub fn get_content(&self) -> &[String] {
-        &(self).content
+    pub fn get_content(&self) -> &[SvgElement] {
+        &self.content
     }
 
     /// Retrieves the foreground color of the SVG.
@@ -55,6 +82,18 @@ impl Icon {
         &self.background_color
     }
 
+    /// Retrieves the foreground color of the SVG in the dark color scheme.
+    #[inline]
+    pub fn get_foreground_color_dark(&self) -> DarkColor {
+        self.foreground_color_dark
+    }
+
+    /// Retrieves the background color of the SVG in the dark color scheme.
+    #[inline]
+    pub fn get_background_color_dark(&self) -> DarkColor {
+        self.background_color_dark
+    }
+
     /// Retrieves the width of the SVG.
     #[inline]
     pub fn get_width(&self) -> Width {
@@ -70,15 +109,28 @@ impl Icon {
     /// Adds a new content path to the SVG.
     #[inline]
     pub fn content(mut self, content: impl ToString) -> Self {
-        self.content.push(content.to_string());
+        self.content.push(svg_path(content));
         self
     }
 
     /// Adds multiple content paths to the SVG.
     #[inline]
     pub fn contents(mut self, content: Vec<impl ToString>) -> Self {
-        self.content
-            .append(&mut content.iter().map(|x| x.to_string()).collect());
+        self.content.extend(content.into_iter().map(svg_path));
+        self
+    }
+
+    /// Adds a new content element (path, circle, rect, line, group) to the SVG.
+    #[inline]
+    pub fn element(mut self, element: SvgElement) -> Self {
+        self.content.push(element);
+        self
+    }
+
+    /// Adds multiple content elements to the SVG.
+    #[inline]
+    pub fn elements(mut self, elements: impl IntoIterator<Item = SvgElement>) -> Self {
+        self.content.extend(elements);
         self
     }
 
@@ -124,6 +176,20 @@ impl Icon {
         self
     }
 
+    /// Sets the foreground color of the SVG in the dark color scheme.
+    #[inline]
+    pub fn foreground_color_dark(mut self, foreground_color_dark: Color) -> Self {
+        self.foreground_color_dark = Some(foreground_color_dark);
+        self
+    }
+
+    /// Sets the background color of the SVG in the dark color scheme.
+    #[inline]
+    pub fn background_color_dark(mut self, background_color_dark: Color) -> Self {
+        self.background_color_dark = Some(background_color_dark);
+        self
+    }
+
     /// Sets the width of the SVG.
     #[inline]
     pub fn width(mut self, width: Width) -> Self {
@@ -160,10 +226,16 @@ impl Icon {
 
     /// Retrieves the viewbox dimensions of the SVG.
     #[inline]
-    pub fn get_view_box(&self) -> (u8, u8, u8, u8) {
+    pub fn get_view_box(&self) -> ViewBox {
         self.view_box
     }
 
+    /// Retrieves the fitting of the viewbox into the width and height of the SVG.
+    #[inline]
+    pub fn get_preserve_aspect_ratio(&self) -> Option<PreserveAspectRatio> {
+        self.preserve_aspect_ratio
+    }
+
     /// Retrieves the stroke line cap style of the SVG.
     #[inline]
     pub fn get_stroke_linecap(&self) -> Option<StrokeLinecap> {
@@ -184,10 +256,30 @@ impl Icon {
         self
     }
 
-    /// Sets the viewbox dimensions of the SVG.
+    /// Sets the viewbox dimensions of the SVG (min-x, min-y, width, height).
+    #[inline]
+    pub fn view_box(
+        mut self,
+        view_box: (
+            impl Into<f64>,
+            impl Into<f64>,
+            impl Into<f64>,
+            impl Into<f64>,
+        ),
+    ) -> Self {
+        let (x, y, width, height) = view_box;
+        self.view_box = (x.into(), y.into(), width.into(), height.into());
+        self
+    }
+
+    /// Sets the fitting of the viewbox into the width and height of the SVG.
     #[inline]
-    pub fn view_box(mut self, view_box: (u8, u8, u8, u8)) -> Self {
-        self.view_box = view_box;
+    pub fn preserve_aspect_ratio(
+        mut self,
+        align: AspectRatioAlign,
+        meet_or_slice: MeetOrSlice,
+    ) -> Self {
+        self.preserve_aspect_ratio = Some((align, meet_or_slice));
         self
     }
 
@@ -197,4 +289,54 @@ impl Icon {
         self.stroke_linecap = Some(stroke_linecap);
         self
     }
+
+    /// Retrieves the alignment of the SVG inside its parent container.
+    #[inline]
+    pub fn get_align_self(&self) -> Option<Align> {
+        self.align_self
+    }
+
+    /// Sets the alignment of the SVG inside its parent container.
+    #[inline]
+    pub fn align_self(mut self, align_self: Align) -> Self {
+        self.align_self = Some(align_self);
+        self
+    }
+
+    /// Sets the accessible name (alternative text) of the SVG.
+    #[inline]
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Toggles whether the SVG is decorative (hidden from assistive technologies).
+    ///
+    /// Decorative icons are rendered without their title and `aria-label`.
+    #[inline]
+    pub fn is_decorative(mut self) -> Self {
+        self.is_decorative = !self.is_decorative;
+        self
+    }
+
+    /// Retrieves the accessible name of the SVG.
+    #[inline]
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Retrieves whether the SVG is decorative.
+    #[inline]
+    pub fn get_decorative(&self) -> bool {
+        self.is_decorative
+    }
+
+    /// Whether the SVG has an accessible name (title or `aria-label`) or is decorative.
+    #[inline]
+    pub fn is_labeled(&self) -> bool {
+        self.is_decorative
+            || self.title.is_some()
+            || self.get_attributes().contains_key("aria-label")
+            || self.get_attributes().contains_key("aria-labelledby")
+    }
 }
@@ -0,0 +1,730 @@
+//! Import of SVG documents into an [Icon]
+//!
+//! Supported elements:
+//! - `svg` (root), its `viewBox`, `width`, `height`, `fill`, `stroke` and stroke attributes
+//! - `g` (group)
+//! - `path`, `circle`, `rect`, `line`
+//! - `ellipse`, `polyline`, `polygon` (converted into path data)
+//! - `title`, `desc`, `metadata` (ignored)
+//!
+//! Elements keep their `fill`, `stroke`, `stroke-width`, `opacity`,
+//! `fill-rule` and `transform`, also from declarations of `style`.
+//! Any other element or a `clip-path`, `mask` or `filter` is reported as
+//! [SvgError]. Elements with both `stroke="none"` and `fill="none"` are
+//! invisible and skipped.
+
+use std::{fmt::Display, io, path::Path};
+
+use crate::aspects::{
+    AspectRatioAlign, FillRule, MeetOrSlice, PreserveAspectRatio, StrokeLinecap, StrokeLinejoin,
+    SvgElement,
+};
+
+use super::{icon, Icon};
+
+/// Error of importing an SVG document
+#[derive(Debug)]
+pub enum SvgError {
+    /// File can't be read
+    Io(io::Error),
+    /// Document is not well-formed
+    Syntax(String),
+    /// Document has no `<svg>` root element
+    NoRoot,
+    /// Element can't be represented by an [Icon]
+    UnsupportedElement(String),
+    /// Attribute can't be represented by an [Icon]
+    UnsupportedAttribute { element: String, attribute: String },
+    /// Required attribute is absent
+    MissingAttribute { element: String, attribute: String },
+    /// Attribute is not a valid number (list)
+    InvalidNumber {
+        element: String,
+        attribute: String,
+        value: String,
+    },
+}
+
+impl Display for SvgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SvgError::Io(err) => write!(f, "can't read svg: {err}"),
+            SvgError::Syntax(msg) => write!(f, "malformed svg: {msg}"),
+            SvgError::NoRoot => write!(f, "no <svg> root element"),
+            SvgError::UnsupportedElement(element) => {
+                write!(f, "unsupported element <{element}>")
+            }
+            SvgError::UnsupportedAttribute { element, attribute } => {
+                write!(f, "unsupported attribute `{attribute}` of <{element}>")
+            }
+            SvgError::MissingAttribute { element, attribute } => {
+                write!(f, "missing attribute `{attribute}` of <{element}>")
+            }
+            SvgError::InvalidNumber {
+                element,
+                attribute,
+                value,
+            } => write!(
+                f,
+                "invalid number `{value}` in attribute `{attribute}` of <{element}>"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SvgError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SvgError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SvgError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// Returns [Icon] parsed from an SVG document
+#[inline]
+pub fn icon_from_svg(svg: &str) -> Result<Icon, SvgError> {
+    Icon::from_svg(svg)
+}
+
+/// Returns [Icon] parsed from an SVG file
+#[inline]
+pub fn icon_from_svg_file(path: impl AsRef<Path>) -> Result<Icon, SvgError> {
+    Icon::from_svg_file(path)
+}
+
+impl Icon {
+    /// Parses an SVG document into an icon.
+    pub fn from_svg(svg: &str) -> Result<Icon, SvgError> {
+        let mut elements = Tokenizer::new(svg);
+        let root = match elements.next().transpose()? {
+            Some(Token::Open(element)) => element,
+            Some(Token::Close) | None => return Err(SvgError::NoRoot),
+        };
+        if root.name != "svg" {
+            return Err(SvgError::NoRoot);
+        }
+        let out = root_icon(&root)?;
+        if root.is_empty {
+            return Ok(out);
+        }
+
+        let mut content = vec![];
+        // open (not self-closing) elements inside of the root
+        let mut open: Vec<Open> = vec![];
+        loop {
+            let Some(token) = elements.next().transpose()? else {
+                return Err(SvgError::Syntax("unclosed <svg>".to_string()));
+            };
+            let element = match token {
+                Token::Open(element) => element,
+                Token::Close => match open.pop() {
+                    None => break,
+                    Some(Open::Group(group, children)) => {
+                        let group = paint(&group, SvgElement::group(children))?;
+                        children_of(&mut open, &mut content).push(group);
+                        continue;
+                    }
+                    Some(_) => continue,
+                },
+            };
+            // children of ignored elements (e.g. <title>) are skipped
+            if open.iter().any(|x| matches!(x, Open::Ignored)) {
+                if !element.is_empty {
+                    open.push(Open::Ignored);
+                }
+                continue;
+            }
+            // e.g. Tabler frame `<path stroke="none" fill="none" .../>`
+            let is_invisible = element.paint_attribute("stroke") == Some("none")
+                && element.paint_attribute("fill") == Some("none");
+            match element.name.as_str() {
+                "title" | "desc" | "metadata" => {
+                    if !element.is_empty {
+                        open.push(Open::Ignored);
+                    }
+                }
+                "g" if element.is_empty => {}
+                "g" => open.push(Open::Group(element, vec![])),
+                _ if is_invisible => {
+                    if !element.is_empty {
+                        open.push(Open::Shape);
+                    }
+                }
+                _ => {
+                    let shape = paint(&element, shape(&element)?)?;
+                    children_of(&mut open, &mut content).push(shape);
+                    if !element.is_empty {
+                        open.push(Open::Shape);
+                    }
+                }
+            }
+        }
+        Ok(out.elements(content))
+    }
+
+    /// Reads and parses an SVG file into an icon.
+    pub fn from_svg_file(path: impl AsRef<Path>) -> Result<Icon, SvgError> {
+        let svg = std::fs::read_to_string(path)?;
+        Self::from_svg(&svg)
+    }
+}
+
+/// Open element while parsing the content.
+enum Open {
+    /// `<g>` with its children so far
+    Group(Element, Vec<SvgElement>),
+    /// `<title>`, `<desc>` or `<metadata>`
+    Ignored,
+    /// Shape with children (e.g. `<path>...</path>`)
+    Shape,
+}
+
+/// Children of the innermost open group or the content of the icon.
+fn children_of<'a>(
+    open: &'a mut [Open],
+    content: &'a mut Vec<SvgElement>,
+) -> &'a mut Vec<SvgElement> {
+    open.iter_mut()
+        .rev()
+        .find_map(|x| match x {
+            Open::Group(_, children) => Some(children),
+            _ => None,
+        })
+        .unwrap_or(content)
+}
+
+/// Icon with the settings of the `<svg>` root element.
+fn root_icon(root: &Element) -> Result<Icon, SvgError> {
+    let view_box = match root.attribute("viewBox") {
+        Some(value) => {
+            let numbers = root.numbers("viewBox")?;
+            let [x, y, w, h] = numbers[..] else {
+                return Err(root.invalid_number("viewBox", value));
+            };
+            (x, y, w, h)
+        }
+        None => (
+            0.0,
+            0.0,
+            root.size("width")?.unwrap_or(24.0),
+            root.size("height")?.unwrap_or(24.0),
+        ),
+    };
+    let (_, _, w, h) = view_box;
+
+    let mut out = icon()
+        .view_box(view_box)
+        .width(root.size("width")?.unwrap_or(w).round() as u64)
+        .height(root.size("height")?.unwrap_or(h).round() as u64);
+    if let Some((align, meet_or_slice)) = root
+        .attribute("preserveAspectRatio")
+        .and_then(preserve_aspect_ratio)
+    {
+        out = out.preserve_aspect_ratio(align, meet_or_slice);
+    }
+    out = match root.paint_attribute("fill") {
+        Some("none") => out.background_color_none(),
+        Some("currentColor") => out.background_color_current_color(),
+        Some(fill) => out.background_color(fill),
+        // svg default fill is black
+        None => out.background_color("black"),
+    };
+    out = match root.paint_attribute("stroke") {
+        None | Some("none") => out.foreground_color_none(),
+        Some("currentColor") => out.foreground_color_current_color(),
+        Some(stroke) => out.foreground_color(stroke),
+    };
+    if let Some(stroke_width) = root.paint_number("stroke-width")? {
+        out = out.stroke_width(stroke_width);
+    }
+    out = match root.paint_attribute("stroke-linecap") {
+        Some("butt") => out.stroke_linecap(StrokeLinecap::Butt),
+        Some("round") => out.stroke_linecap(StrokeLinecap::Round),
+        Some("square") => out.stroke_linecap(StrokeLinecap::Square),
+        _ => out,
+    };
+    out = match root.paint_attribute("stroke-linejoin") {
+        Some("arcs") => out.stroke_linejoin(StrokeLinejoin::Arcs),
+        Some("bevel") => out.stroke_linejoin(StrokeLinejoin::Bevel),
+        Some("miter") => out.stroke_linejoin(StrokeLinejoin::Miter),
+        Some("miter-clip") => out.stroke_linejoin(StrokeLinejoin::MiterClip),
+        Some("round") => out.stroke_linejoin(StrokeLinejoin::Round),
+        _ => out,
+    };
+    Ok(out)
+}
+
+/// Value of `preserveAspectRatio`, `None` for invalid values (ignored like in browsers).
+fn preserve_aspect_ratio(value: &str) -> Option<PreserveAspectRatio> {
+    let mut parts = value.split_whitespace().filter(|x| *x != "defer");
+    let align = match parts.next()? {
+        "none" => AspectRatioAlign::None,
+        "xMinYMin" => AspectRatioAlign::XMinYMin,
+        "xMidYMin" => AspectRatioAlign::XMidYMin,
+        "xMaxYMin" => AspectRatioAlign::XMaxYMin,
+        "xMinYMid" => AspectRatioAlign::XMinYMid,
+        "xMidYMid" => AspectRatioAlign::XMidYMid,
+        "xMaxYMid" => AspectRatioAlign::XMaxYMid,
+        "xMinYMax" => AspectRatioAlign::XMinYMax,
+        "xMidYMax" => AspectRatioAlign::XMidYMax,
+        "xMaxYMax" => AspectRatioAlign::XMaxYMax,
+        _ => return None,
+    };
+    let meet_or_slice = match parts.next() {
+        None | Some("meet") => MeetOrSlice::Meet,
+        Some("slice") => MeetOrSlice::Slice,
+        Some(_) => return None,
+    };
+    parts.next().is_none().then_some((align, meet_or_slice))
+}
+
+/// Applies the paint and transform of an element.
+fn paint(element: &Element, mut out: SvgElement) -> Result<SvgElement, SvgError> {
+    for attribute in ["clip-path", "mask", "filter"] {
+        if element.paint_attribute(attribute).is_some() {
+            return Err(SvgError::UnsupportedAttribute {
+                element: element.name.clone(),
+                attribute: attribute.to_string(),
+            });
+        }
+    }
+    out = match element.paint_attribute("fill") {
+        None => out,
+        Some("none") => out.fill_none(),
+        Some("currentColor") => out.fill_current_color(),
+        Some(fill) => out.fill(fill),
+    };
+    out = match element.paint_attribute("stroke") {
+        None => out,
+        Some("none") => out.stroke_none(),
+        Some("currentColor") => out.stroke_current_color(),
+        Some(stroke) => out.stroke(stroke),
+    };
+    if let Some(stroke_width) = element.paint_number("stroke-width")? {
+        out = out.stroke_width(stroke_width);
+    }
+    if let Some(opacity) = element.paint_number("opacity")? {
+        out = out.opacity(opacity);
+    }
+    out = match element.paint_attribute("fill-rule") {
+        Some("nonzero") => out.fill_rule(FillRule::NonZero),
+        Some("evenodd") => out.fill_rule(FillRule::EvenOdd),
+        _ => out,
+    };
+    if let Some(transform) = element.attribute("transform") {
+        out = out.transform(transform);
+    }
+    Ok(out)
+}
+
+/// Primitive of a shape element, shapes without a primitive are converted into path data.
+fn shape(element: &Element) -> Result<SvgElement, SvgError> {
+    let number_or_zero = |name: &str| match element.attribute(name) {
+        Some(_) => element.number(name),
+        None => Ok(0.0),
+    };
+    let out = match element.name.as_str() {
+        "path" => SvgElement::path(element.required("d")?),
+        "circle" => {
+            let (cx, cy) = (number_or_zero("cx")?, number_or_zero("cy")?);
+            SvgElement::circle(cx, cy, element.number("r")?)
+        }
+        "ellipse" => {
+            let (cx, cy) = (number_or_zero("cx")?, number_or_zero("cy")?);
+            let (rx, ry) = (element.number("rx")?, element.number("ry")?);
+            SvgElement::path(ellipse_path(cx, cy, rx, ry))
+        }
+        "rect" => {
+            let (x, y) = (number_or_zero("x")?, number_or_zero("y")?);
+            let (w, h) = (element.number("width")?, element.number("height")?);
+            let rx = element.attribute("rx").map(|_| element.number("rx"));
+            let ry = element.attribute("ry").map(|_| element.number("ry"));
+            let (rx, ry) = match (rx.transpose()?, ry.transpose()?) {
+                (Some(rx), Some(ry)) => (rx, ry),
+                (Some(r), None) | (None, Some(r)) => (r, r),
+                (None, None) => (0.0, 0.0),
+            };
+            SvgElement::rounded_rect(x, y, w, h, rx.min(w / 2.0), ry.min(h / 2.0))
+        }
+        "line" => {
+            let (x1, y1) = (number_or_zero("x1")?, number_or_zero("y1")?);
+            let (x2, y2) = (number_or_zero("x2")?, number_or_zero("y2")?);
+            SvgElement::line(x1, y1, x2, y2)
+        }
+        "polyline" | "polygon" => {
+            let points = element.numbers("points")?;
+            let pairs = points.chunks_exact(2);
+            if points.is_empty() || !pairs.remainder().is_empty() {
+                return Err(element.invalid_number("points", element.required("points")?));
+            }
+            let mut out = String::new();
+            for (i, point) in pairs.enumerate() {
+                let command = if i == 0 { 'M' } else { 'L' };
+                out.push_str(&format!("{command}{} {}", point[0], point[1]));
+            }
+            if element.name == "polygon" {
+                out.push('z');
+            }
+            SvgElement::path(out)
+        }
+        _ => return Err(SvgError::UnsupportedElement(element.name.clone())),
+    };
+    Ok(out)
+}
+
+/// Path data of an ellipse as two arcs.
+fn ellipse_path(cx: f64, cy: f64, rx: f64, ry: f64) -> String {
+    let (x, d) = (cx - rx, rx * 2.0);
+    format!("M{x} {cy}a{rx} {ry} 0 1 0 {d} 0a{rx} {ry} 0 1 0 -{d} 0")
+}
+
+/// XML element with attributes.
+#[derive(Debug)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    /// Self-closing, e.g. `<path />`
+    is_empty: bool,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(x, _)| x == name)
+            .map(|(_, x)| x.as_str())
+    }
+
+    fn required(&self, name: &str) -> Result<&str, SvgError> {
+        self.attribute(name)
+            .ok_or_else(|| SvgError::MissingAttribute {
+                element: self.name.clone(),
+                attribute: name.to_string(),
+            })
+    }
+
+    fn invalid_number(&self, name: &str, value: &str) -> SvgError {
+        SvgError::InvalidNumber {
+            element: self.name.clone(),
+            attribute: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn number(&self, name: &str) -> Result<f64, SvgError> {
+        let value = self.required(name)?;
+        value
+            .trim()
+            .parse()
+            .map_err(|_| self.invalid_number(name, value))
+    }
+
+    fn numbers(&self, name: &str) -> Result<Vec<f64>, SvgError> {
+        let value = self.required(name)?;
+        value
+            .split(|x: char| x.is_whitespace() || x == ',')
+            .filter(|x| !x.is_empty())
+            .map(|x| x.parse().map_err(|_| self.invalid_number(name, value)))
+            .collect()
+    }
+
+    /// Presentation attribute, declarations of `style` take precedence.
+    fn paint_attribute(&self, name: &str) -> Option<&str> {
+        let style = self.attribute("style").unwrap_or_default();
+        style
+            .split(';')
+            .filter_map(|x| x.split_once(':'))
+            .rfind(|(key, _)| key.trim() == name)
+            .map(|(_, value)| value.trim())
+            .or_else(|| self.attribute(name))
+    }
+
+    /// Numeric presentation attribute.
+    fn paint_number(&self, name: &str) -> Result<Option<f64>, SvgError> {
+        self.paint_attribute(name)
+            .map(|value| value.parse().map_err(|_| self.invalid_number(name, value)))
+            .transpose()
+    }
+
+    /// Length in px, `None` for absent or relative (`%`) sizes.
+    fn size(&self, name: &str) -> Result<Option<f64>, SvgError> {
+        match self.attribute(name) {
+            None => Ok(None),
+            Some(value) if value.trim().ends_with('%') => Ok(None),
+            Some(value) => value
+                .trim()
+                .trim_end_matches("px")
+                .parse()
+                .map(Some)
+                .map_err(|_| self.invalid_number(name, value)),
+        }
+    }
+}
+
+enum Token {
+    Open(Element),
+    Close,
+}
+
+/// Minimal XML tokenizer, skips declarations, comments and text.
+struct Tokenizer<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { rest: source }
+    }
+
+    fn skip_until(&mut self, end: &str) -> Result<(), SvgError> {
+        let i = self
+            .rest
+            .find(end)
+            .ok_or_else(|| SvgError::Syntax(format!("expected `{end}`")))?;
+        self.rest = &self.rest[i + end.len()..];
+        Ok(())
+    }
+
+    fn element(&mut self) -> Result<Element, SvgError> {
+        // `>` inside of attribute values doesn't end the tag
+        let mut quote = None;
+        let end = self
+            .rest
+            .char_indices()
+            .find(|(_, x)| match (quote, *x) {
+                (Some(q), x) if x == q => {
+                    quote = None;
+                    false
+                }
+                (None, '"' | '\'') => {
+                    quote = Some(*x);
+                    false
+                }
+                (None, '>') => true,
+                _ => false,
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(|| SvgError::Syntax("unclosed tag".to_string()))?;
+        let tag = &self.rest[..end];
+        self.rest = &self.rest[end + 1..];
+        let (tag, is_empty) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_string();
+        if name.is_empty() {
+            return Err(SvgError::Syntax("empty tag name".to_string()));
+        }
+        let mut attributes = Vec::new();
+        let mut rest = tag[name_end..].trim_start();
+        while !rest.is_empty() {
+            let (key, value) = rest
+                .split_once('=')
+                .ok_or_else(|| SvgError::Syntax(format!("attribute without value in <{name}>")))?;
+            let value = value.trim_start();
+            let quote = value
+                .chars()
+                .next()
+                .filter(|x| *x == '"' || *x == '\'')
+                .ok_or_else(|| SvgError::Syntax(format!("unquoted attribute in <{name}>")))?;
+            let value = &value[1..];
+            let value_end = value
+                .find(quote)
+                .ok_or_else(|| SvgError::Syntax(format!("unclosed attribute in <{name}>")))?;
+            attributes.push((key.trim().to_string(), value[..value_end].to_string()));
+            rest = value[value_end + 1..].trim_start();
+        }
+        Ok(Element {
+            name,
+            attributes,
+            is_empty,
+        })
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, SvgError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.rest.find('<')?;
+            self.rest = &self.rest[start..];
+            let skipped = if self.rest.starts_with("<!--") {
+                self.skip_until("-->")
+            } else if self.rest.starts_with("<?") {
+                self.skip_until("?>")
+            } else if self.rest.starts_with("<!") {
+                self.skip_until(">")
+            } else if let Some(rest) = self.rest.strip_prefix("</") {
+                let Some(end) = rest.find('>') else {
+                    return Some(Err(SvgError::Syntax("unclosed tag".to_string())));
+                };
+                self.rest = &rest[end + 1..];
+                return Some(Ok(Token::Close));
+            } else {
+                self.rest = &self.rest[1..];
+                return Some(self.element().map(Token::Open));
+            };
+            if let Err(err) = skipped {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_import {
+    use crate::{
+        aspects::{svg_path, AspectRatioAlign, FillRule, MeetOrSlice, StrokeLinejoin, SvgElement},
+        components::icon::Icon,
+    };
+
+    use super::SvgError;
+
+    #[test]
+    fn shapes() {
+        let icon = Icon::from_svg(
+            r#"<?xml version="1.0"?>
+            <!-- comment -->
+            <svg xmlns="http://www.w3.org/2000/svg" width="48" height="48" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round">
+                <title>shapes</title>
+                <g>
+                    <circle cx="12" cy="12" r="3" />
+                    <rect x="2" y="4" width="20" height="16" rx="2" />
+                    <line x1="0" y1="0" x2="24" y2="24" />
+                </g>
+                <polyline points="1,2 3,4" />
+                <polygon points="1 2 3 4 5 6" />
+                <ellipse cx="4" cy="4" rx="2" ry="1" />
+                <path d="M0 0L1 1" />
+            </svg>"#,
+        )
+        .unwrap();
+        assert_eq!(icon.get_view_box(), (0.0, 0.0, 24.0, 24.0));
+        assert_eq!(icon.get_width(), 48);
+        assert_eq!(icon.get_stroke_width(), Some(2.0));
+        assert_eq!(
+            icon.get_content(),
+            [
+                SvgElement::group([
+                    SvgElement::circle(12.0, 12.0, 3.0),
+                    SvgElement::rounded_rect(2.0, 4.0, 20.0, 16.0, 2.0, 2.0),
+                    SvgElement::line(0.0, 0.0, 24.0, 24.0),
+                ]),
+                svg_path("M1 2L3 4"),
+                svg_path("M1 2L3 4L5 6z"),
+                svg_path("M2 4a2 1 0 1 0 4 0a2 1 0 1 0 -4 0"),
+                svg_path("M0 0L1 1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tabler_file() {
+        let path = std::env::temp_dir().join("zen_rs_test_icon.svg");
+        std::fs::write(
+            &path,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path stroke="none" d="M0 0h24v24H0z" fill="none"/><path d="M18 6l-12 12" /></svg>"#,
+        )
+        .unwrap();
+        let icon = Icon::from_svg_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(icon.get_content(), [svg_path("M18 6l-12 12")]);
+        assert_eq!(icon.get_stroke_linejoin(), Some(StrokeLinejoin::Round));
+    }
+
+    #[test]
+    fn paint() {
+        let icon = Icon::from_svg(
+            r##"<svg viewBox="0 0 24 24">
+                <g transform="translate(1 1)" opacity="0.5">
+                    <path d="M0 0h4v4z" fill="#f00" fill-rule="evenodd"/>
+                    <g><circle r="2" style="fill: none; stroke:currentColor ;stroke-width:1.5"/></g>
+                </g>
+                <rect width="24" height="24" fill="#000" stroke="none"/>
+            </svg>"##,
+        )
+        .unwrap();
+        assert_eq!(
+            icon.get_content(),
+            [
+                SvgElement::group([
+                    svg_path("M0 0h4v4z")
+                        .fill("#f00")
+                        .fill_rule(FillRule::EvenOdd),
+                    SvgElement::group([SvgElement::circle(0.0, 0.0, 2.0)
+                        .fill_none()
+                        .stroke_current_color()
+                        .stroke_width(1.5)]),
+                ])
+                .opacity(0.5)
+                .transform("translate(1 1)"),
+                SvgElement::rect(0.0, 0.0, 24.0, 24.0)
+                    .fill("#000")
+                    .stroke_none(),
+            ]
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        let icon = Icon::from_svg(
+            r#"<svg aria-label="it's" data-x='a > "b"' viewBox="0 0 24 24"><path d="M1 1"/></svg>"#,
+        )
+        .unwrap();
+        assert_eq!(icon.get_content(), [svg_path("M1 1")]);
+        assert_eq!(icon.get_view_box(), (0.0, 0.0, 24.0, 24.0));
+    }
+
+    #[test]
+    fn view_box() {
+        let icon = Icon::from_svg(
+            r#"<svg viewBox="-1 -0.5 512 512" preserveAspectRatio="xMaxYMin slice"/>"#,
+        )
+        .unwrap();
+        assert_eq!(icon.get_view_box(), (-1.0, -0.5, 512.0, 512.0));
+        assert_eq!(icon.get_width(), 512);
+        assert_eq!(
+            icon.get_preserve_aspect_ratio(),
+            Some((AspectRatioAlign::XMaxYMin, MeetOrSlice::Slice))
+        );
+        let icon = Icon::from_svg(r#"<svg preserveAspectRatio="none"/>"#).unwrap();
+        assert_eq!(
+            icon.get_preserve_aspect_ratio(),
+            Some((AspectRatioAlign::None, MeetOrSlice::Meet))
+        );
+        let icon = Icon::from_svg(r#"<svg preserveAspectRatio="center"/>"#).unwrap();
+        assert_eq!(icon.get_preserve_aspect_ratio(), None);
+    }
+
+    #[test]
+    fn errors() {
+        let err = Icon::from_svg(r#"<svg viewBox="0 0 24 24"><text>hi</text></svg>"#);
+        assert!(matches!(err, Err(SvgError::UnsupportedElement(x)) if x == "text"));
+        let err = Icon::from_svg(r#"<svg viewBox="0 0 24 24"><g mask="url(#m)"></g></svg>"#);
+        assert!(matches!(err, Err(SvgError::UnsupportedAttribute { .. })));
+        let err = Icon::from_svg(r#"<svg viewBox="0 0 24 24"><circle cx="1"/></svg>"#);
+        assert!(matches!(err, Err(SvgError::MissingAttribute { .. })));
+        let err = Icon::from_svg(r#"<svg viewBox="0 0 a 24"></svg>"#);
+        assert!(matches!(err, Err(SvgError::InvalidNumber { .. })));
+        let err = Icon::from_svg(r#"<svg viewBox="0 0 24 24"><path d="M0 0"/>"#);
+        assert!(matches!(err, Err(SvgError::Syntax(_))));
+        assert!(matches!(Icon::from_svg("<div/>"), Err(SvgError::NoRoot)));
+        assert!(matches!(
+            Icon::from_svg_file("/nonexistent.svg"),
+            Err(SvgError::Io(_))
+        ));
+        assert_eq!(
+            SvgError::UnsupportedElement("text".to_string()).to_string(),
+            "unsupported element <text>"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! [Tabler Icons](https://tabler.io/icons) (MIT License, see `icons/tabler/LICENSE`)
+//!
+//! Generated from the SVG sources in `icons/tabler/outline` and
+//! `icons/tabler/filled` at build time. Outline icons are preconfigured like
+//! [super::github::github_outlined], filled icons are painted with the current
+//! color and get the `-filled` suffix (e.g. `star-filled`, [star_filled]).
+//!
+//! More icons can be added by copying their SVG from the Tabler repository
+//! into the matching directory, or imported at runtime with [Icon::from_svg].
+//!
+//! Icons are available by typed constructors, e.g. [brand_rust], or by
+//! name with [icon_by_name].
+//!
+#![doc = include_str!(concat!(env!("OUT_DIR"), "/tabler.md"))]
+
+use crate::aspects::{StrokeLinecap, StrokeLinejoin, SvgElement};
+
+use super::{icon, Icon};
+
+/// Constructor of a bundled icon
+pub type IconConstructor = fn() -> Icon;
+
+include!(concat!(env!("OUT_DIR"), "/tabler.rs"));
+
+/// Returns the bundled icon by its Tabler name, e.g. `brand-github`
+///
+/// `None` for icons which are not bundled, see the module docs
+#[inline]
+pub fn icon_by_name(name: &str) -> Option<Icon> {
+    ICONS
+        .binary_search_by(|(x, _)| (*x).cmp(name))
+        .ok()
+        .map(|x| ICONS[x].1())
+}
+
+/// Returns names of every bundled icon
+#[inline]
+pub fn icon_names() -> impl Iterator<Item = &'static str> {
+    ICONS.iter().map(|(x, _)| *x)
+}
+
+/// Icon with Tabler filled settings
+fn filled(elements: Vec<SvgElement>) -> Icon {
+    icon()
+        .elements(elements)
+        .view_box((0, 0, 24, 24))
+        .size(60)
+        .foreground_color_none()
+        .background_color_current_color()
+}
+
+/// Icon with Tabler outline settings
+fn outline(elements: Vec<SvgElement>) -> Icon {
+    icon()
+        .elements(elements)
+        .stroke_width(2.0)
+        .stroke_linecap(StrokeLinecap::Round)
+        .stroke_linejoin(StrokeLinejoin::Round)
+        .view_box((0, 0, 24, 24))
+        .size(60)
+        .foreground_color_current_color()
+        .background_color_none()
+}
+
+#[cfg(test)]
+mod test_tabler {
+    use super::{brand_github, brand_rust, icon_by_name, icon_names, menu_2, star_filled, ICONS};
+    use crate::components::github::github_outlined;
+
+    #[test]
+    fn by_name() {
+        assert_eq!(icon_by_name("brand-github"), Some(brand_github()));
+        assert_eq!(icon_by_name("menu-2"), Some(menu_2()));
+        assert_eq!(icon_by_name("missing"), None);
+        assert_eq!(icon_by_name("brand-rust"), Some(brand_rust()));
+        assert_eq!(icon_by_name("star-filled"), Some(star_filled()));
+    }
+
+    #[test]
+    fn sorted() {
+        let names: Vec<_> = icon_names().collect();
+        assert!(names.windows(2).all(|x| x[0] < x[1]));
+        assert_eq!(names.len(), ICONS.len());
+        assert!(ICONS.iter().all(|(_, x)| !x().get_content().is_empty()));
+    }
+
+    #[test]
+    fn preconfigured() {
+        assert_eq!(brand_github(), github_outlined());
+        assert_eq!(brand_github().get_content().len(), 1);
+    }
+
+    #[test]
+    fn filled() {
+        let star = star_filled();
+        assert_eq!(star.get_content().len(), 1);
+        assert_ne!(
+            star.get_background_color(),
+            brand_rust().get_background_color()
+        );
+        assert_eq!(
+            star.get_foreground_color(),
+            brand_rust().get_background_color()
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Accessibility checks of component trees
+//!
+//! # Example
+//! ```rust
+//! use zen_rs::{
+//!     components::{github::github_outlined, lint, Lint},
+//!     dynamic_stack::hstack,
+//! };
+//!
+//! let tree = hstack((
+//!     github_outlined().id("gh"),
+//!     github_outlined().title("GitHub"),
+//!     github_outlined().is_decorative(),
+//! ));
+//! let lints = lint(&tree.into());
+//! assert_eq!(lints.len(), 1);
+//! assert!(matches!(&lints[0], Lint::UnlabeledIcon(x) if x.get_id() == Some("gh")));
+//! assert_eq!(lints[0].to_string(), "icon `gh` has no title and isn't decorative");
+//! ```
+
+use std::fmt::Display;
+
+use super::{Components, Icon};
+
+/// Accessibility issue of a component
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Lint {
+    /// Meaningful icon without accessible name, see [Icon::title] and [Icon::is_decorative]
+    UnlabeledIcon(Icon),
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lint::UnlabeledIcon(icon) => match icon.get_id() {
+                Some(id) => write!(f, "icon `{id}` has no title and isn't decorative"),
+                None => write!(f, "icon has no title and isn't decorative"),
+            },
+        }
+    }
+}
+
+/// Reports accessibility issues of a component tree.
+pub fn lint(component: &Components) -> Vec<Lint> {
+    let mut out = vec![];
+    component.walk(|component| {
+        if let Components::Icon(icon) = component {
+            if !icon.is_labeled() {
+                out.push(Lint::UnlabeledIcon(icon.clone()));
+            }
+        }
+    });
+    out
+}
@@ -9,7 +9,10 @@
 pub mod h;
 
 use crate::aspects::{
-    BackgroundColor, DefaultFontFamily, FontStyle, ForegroundColor, Link, Size, Weight,
+    Align, Attributes, BackgroundColor, Classes, Color, ColorScheme, ColorToken, DarkColor,
+    Decoration, DecorationStyle, DefaultFontFamily, FontSizeToken, FontStyle, FontToken,
+    ForegroundColor, Id, Link, Size, TextAlign, TextRole, TextTransform, Truncate, Typography,
+    Weight,
 };
 
 /// Return default [Text] instance
@@ -29,10 +32,40 @@ pub struct Text {
     background_color: BackgroundColor,
     /// Font style of the text (size, weight, etc.)
     font_style: FontStyle,
+    /// Typography of the text (line height, spacing, etc.)
+    typography: Typography,
+    /// Decoration of the underline/strikeout lines (color, thickness, style)
+    decoration: Decoration,
     /// Optional link associated with the text
     link: Link,
+    /// Alignment of the text inside its parent container
+    align_self: Option<Align>,
+    /// Semantic role of the element, e.g. a heading.
+    role: TextRole,
+    /// Truncation of the text which does not fit
+    truncate: Truncate,
+    /// Foreground color of the text in the dark color scheme
+    foreground_color_dark: DarkColor,
+    /// Background color of the text in the dark color scheme
+    background_color_dark: DarkColor,
+    /// Theme color of the text, overrides foreground color until it's set again
+    foreground_token: Option<ColorToken>,
+    /// Theme color of the background, overrides background color until it's set again
+    background_token: Option<ColorToken>,
+    /// Theme font size, overrides font size until it's set again
+    size_token: Option<FontSizeToken>,
+    /// Theme font family, overrides font family until it's set again
+    font_token: Option<FontToken>,
+    /// Unique id of the element.
+    id: Id,
+    /// Class list of the element.
+    classes: Classes,
+    /// Custom (`data-*`, `aria-*`) attributes of the element.
+    attributes: Attributes,
 }
 
+impl_attributes!(Text);
+
 impl Text {
     /// Set a link for the text component
     #[inline]
@@ -52,6 +85,7 @@ impl Text {
     #[inline]
     pub fn foreground_color(mut self, foreground_color: ForegroundColor) -> Self {
         self.foreground_color = foreground_color;
+        self.foreground_token = None;
         self
     }
 
@@ -59,6 +93,21 @@ impl Text {
     #[inline]
     pub fn background_color(mut self, background_color: BackgroundColor) -> Self {
         self.background_color = background_color;
+        self.background_token = None;
+        self
+    }
+
+    /// Set the foreground color of the text in the dark color scheme
+    #[inline]
+    pub fn foreground_color_dark(mut self, foreground_color_dark: ForegroundColor) -> Self {
+        self.foreground_color_dark = Some(foreground_color_dark);
+        self
+    }
+
+    /// Set the background color of the text in the dark color scheme
+    #[inline]
+    pub fn background_color_dark(mut self, background_color_dark: BackgroundColor) -> Self {
+        self.background_color_dark = Some(background_color_dark);
         self
     }
 
@@ -66,6 +115,7 @@ impl Text {
     #[inline]
     pub fn size(mut self, size: Size) -> Self {
         self.font_style.0 = size;
+        self.size_token = None;
         self
     }
 
@@ -83,6 +133,39 @@ impl Text {
         self
     }
 
+    /// Set the color of the underline/strikeout lines
+    #[inline]
+    pub fn decoration_color(mut self, decoration_color: Color) -> Self {
+        self.decoration.0 = Some(decoration_color);
+        self
+    }
+
+    /// Set the thickness of the underline/strikeout lines
+    #[inline]
+    pub fn decoration_thickness(mut self, decoration_thickness: Size) -> Self {
+        self.decoration.1 = Some(decoration_thickness);
+        self
+    }
+
+    /// Set the style of the underline/strikeout lines
+    /// - Solid | Default
+    /// - Double
+    /// - Dashed
+    /// - Dotted
+    /// - Wavy
+    #[inline]
+    pub fn decoration_style(mut self, decoration_style: DecorationStyle) -> Self {
+        self.decoration.2 = decoration_style;
+        self
+    }
+
+    /// Set the complete [Decoration] directly
+    #[inline]
+    pub fn decoration(mut self, decoration: Decoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
     /// Toggle the italic style for the text
     #[inline]
     pub fn is_italic(mut self) -> Self {
@@ -91,12 +174,14 @@ impl Text {
     }
 
     /// Set the weight of the font
+    ///
+    /// Any value in `1..=1000` is supported, see [Weight::new], presets:
     ///  - [Weight::Heavy] => 900
     ///  - [Weight::ExtraBold] => 800
     ///  - [Weight::Bold] => 700
     ///  - [Weight::SemiBold] => 600
-    ///  - [Weight::Medium] => 500 | Default
-    ///  - [Weight::Normal] => 400
+    ///  - [Weight::Medium] => 500
+    ///  - [Weight::Normal] => 400 | Default
     ///  - [Weight::Light] => 300
     ///  - [Weight::ExtraLight] => 200
     ///  - [Weight::Thin] => 100
@@ -106,10 +191,32 @@ impl Text {
         self
     }
 
-    /// Set a custom font name
+    /// Set a custom font name, replacing the whole stack of custom font names
+    ///
+    /// Use [Text::font_fallback] and [Text::font_fallbacks] to append fallbacks after it
     #[inline]
     pub fn font_custom(mut self, name: impl ToString) -> Self {
-        self.font_style.5 .0 = name.to_string();
+        self.font_style.5 .0 = vec![name.to_string()];
+        self.font_token = None;
+        self
+    }
+
+    /// Append a custom font name to the end of the fallback stack
+    ///
+    /// The first installed font of the stack is used
+    #[inline]
+    pub fn font_fallback(mut self, name: impl ToString) -> Self {
+        self.font_style.5 .0.push(name.to_string());
+        self.font_token = None;
+        self
+    }
+
+    /// Append custom font names to the end of the fallback stack, in order
+    #[inline]
+    pub fn font_fallbacks(mut self, names: impl IntoIterator<Item = impl ToString>) -> Self {
+        let names = names.into_iter().map(|x| x.to_string());
+        self.font_style.5 .0.extend(names);
+        self.font_token = None;
         self
     }
 
@@ -123,6 +230,57 @@ impl Text {
     #[inline]
     pub fn font_default(mut self, def: DefaultFontFamily) -> Self {
         self.font_style.5 .1 = def;
+        self.font_token = None;
+        self
+    }
+
+    /// Set the line height relative to the font size
+    #[inline]
+    pub fn line_height(mut self, line_height: f64) -> Self {
+        self.typography.0 = Some(line_height);
+        self
+    }
+
+    /// Set the extra space between letters (in px)
+    #[inline]
+    pub fn letter_spacing(mut self, letter_spacing: f64) -> Self {
+        self.typography.1 = Some(letter_spacing);
+        self
+    }
+
+    /// Set the extra space between words (in px)
+    #[inline]
+    pub fn word_spacing(mut self, word_spacing: f64) -> Self {
+        self.typography.2 = Some(word_spacing);
+        self
+    }
+
+    /// Set the horizontal alignment of lines
+    /// - Left
+    /// - Center
+    /// - Right
+    /// - Justify
+    #[inline]
+    pub fn text_align(mut self, text_align: TextAlign) -> Self {
+        self.typography.3 = Some(text_align);
+        self
+    }
+
+    /// Set the case transformation of the text
+    /// - None | Default
+    /// - Uppercase
+    /// - Lowercase
+    /// - Capitalize
+    #[inline]
+    pub fn text_transform(mut self, text_transform: TextTransform) -> Self {
+        self.typography.4 = text_transform;
+        self
+    }
+
+    /// Set the complete [Typography] directly
+    #[inline]
+    pub fn typography(mut self, typography: Typography) -> Self {
+        self.typography = typography;
         self
     }
 
@@ -130,6 +288,76 @@ impl Text {
     #[inline]
     pub fn font_style(mut self, font: FontStyle) -> Self {
         self.font_style = font;
+        self.size_token = None;
+        self.font_token = None;
+        self
+    }
+
+    /// Set the alignment of the text inside its parent container
+    #[inline]
+    pub fn align_self(mut self, align_self: Align) -> Self {
+        self.align_self = Some(align_self);
+        self
+    }
+
+    /// Sets the semantic role of the element, e.g. [TextRole::H1].
+    #[inline]
+    pub fn role(mut self, role: TextRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Set the truncation of the text
+    /// - [Truncate::None] | Default
+    /// - [Truncate::Ellipsis]
+    /// - [Truncate::Lines]
+    #[inline]
+    pub fn truncate(mut self, truncate: Truncate) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Keep the text on a single line and cut it with ellipsis
+    #[inline]
+    pub fn ellipsis(mut self) -> Self {
+        self.truncate = Truncate::Ellipsis;
+        self
+    }
+
+    /// Clamp the text to `lines` lines and cut it with ellipsis, `0` disables truncation
+    #[inline]
+    pub fn line_clamp(mut self, lines: u64) -> Self {
+        self.truncate = Truncate::Lines(lines);
+        self
+    }
+
+    /// Set the theme color of the text
+    ///
+    /// The raw foreground color is used as fallback without theme
+    #[inline]
+    pub fn foreground_token(mut self, token: ColorToken) -> Self {
+        self.foreground_token = Some(token);
+        self
+    }
+
+    /// Set the theme color of the background
+    #[inline]
+    pub fn background_token(mut self, token: ColorToken) -> Self {
+        self.background_token = Some(token);
+        self
+    }
+
+    /// Set the theme font size
+    #[inline]
+    pub fn size_token(mut self, token: FontSizeToken) -> Self {
+        self.size_token = Some(token);
+        self
+    }
+
+    /// Set the theme font family
+    #[inline]
+    pub fn font_token(mut self, token: FontToken) -> Self {
+        self.font_token = Some(token);
         self
     }
 
@@ -151,6 +379,30 @@ impl Text {
         self.background_color
     }
 
+    /// Get the foreground color in the dark color scheme
+    #[inline]
+    pub fn get_foreground_color_dark(&self) -> DarkColor {
+        self.foreground_color_dark
+    }
+
+    /// Get the background color in the dark color scheme
+    #[inline]
+    pub fn get_background_color_dark(&self) -> DarkColor {
+        self.background_color_dark
+    }
+
+    /// Get the foreground color for the color scheme
+    #[inline]
+    pub fn get_foreground_color_for(&self, scheme: ColorScheme) -> ForegroundColor {
+        scheme.pick(self.foreground_color, self.foreground_color_dark)
+    }
+
+    /// Get the background color for the color scheme
+    #[inline]
+    pub fn get_background_color_for(&self, scheme: ColorScheme) -> BackgroundColor {
+        scheme.pick(self.background_color, self.background_color_dark)
+    }
+
     /// Get the font size
     #[inline]
     pub fn get_size(&self) -> Size {
@@ -163,9 +415,63 @@ impl Text {
         &self.font_style
     }
 
+    /// Get the decoration of the underline/strikeout lines
+    #[inline]
+    pub fn get_decoration(&self) -> &Decoration {
+        &self.decoration
+    }
+
+    /// Get the complete typography
+    #[inline]
+    pub fn get_typography(&self) -> &Typography {
+        &self.typography
+    }
+
     /// Get the link (if any) associated with the text
     #[inline]
     pub fn get_link(&self) -> &Link {
         &self.link
     }
+
+    /// Get the alignment of the text inside its parent container
+    #[inline]
+    pub fn get_align_self(&self) -> Option<Align> {
+        self.align_self
+    }
+
+    /// Retrieves the semantic role of the element.
+    #[inline]
+    pub fn get_role(&self) -> TextRole {
+        self.role
+    }
+
+    /// Get the truncation of the text
+    #[inline]
+    pub fn get_truncate(&self) -> Truncate {
+        self.truncate
+    }
+
+    /// Get the theme color of the text
+    #[inline]
+    pub fn get_foreground_token(&self) -> Option<ColorToken> {
+        self.foreground_token
+    }
+
+    /// Get the theme color of the background
+    #[inline]
+    pub fn get_background_token(&self) -> Option<ColorToken> {
+        self.background_token
+    }
+
+    /// Get the theme font size
+    #[inline]
+    pub fn get_size_token(&self) -> Option<FontSizeToken> {
+        self.size_token
+    }
+
+    /// Get the theme font family
+    #[inline]
+    pub fn get_font_token(&self) -> Option<FontToken> {
+        self.font_token
+    }
 }
@@ -1,40 +1,60 @@
 //! Components with fixed size
 //! [Tailwindcss font size](https://tailwindcss.com/docs/font-size)
+//!
+//! Size and color reference the theme tokens, fixed values are used as fallback
+//!
+//! Semantic role follows the size: `4xl` and larger are [TextRole::H1], `3xl`..`lg`
+//! are [TextRole::H2]..[TextRole::H5], smaller are [TextRole::Paragraph].
+//! Mono texts are [TextRole::Code]. Override it with [Text::role].
+//!
+//! [TextRole::H1]: crate::aspects::TextRole::H1
+//! [TextRole::H2]: crate::aspects::TextRole::H2
+//! [TextRole::H5]: crate::aspects::TextRole::H5
+//! [TextRole::Paragraph]: crate::aspects::TextRole::Paragraph
+//! [TextRole::Code]: crate::aspects::TextRole::Code
+//! [Text::role]: crate::components::Text::role
 
 macro_rules! h {
-    ($name:tt, $font_size:expr) => {
+    ($name:tt, $font_size:expr, $token:ident, $role:ident) => {
         paste::paste! {
-            #[doc = stringify!(text with $fint_size px size and dark color font)]
+            #[doc = concat!("text with ", stringify!($font_size), " px size, theme foreground color and ", stringify!($role), " role")]
             pub fn [<text_$name>](content: impl ToString) -> crate::components::Text {
                 crate::components::text()
                     .content(content)
                     .size($font_size)
+                    .size_token(crate::aspects::FontSizeToken::$token)
                     .foreground_color((0, 0, 0, 100))
+                    .foreground_token(crate::aspects::ColorToken::Foreground)
+                    .role(crate::aspects::TextRole::$role)
             }
         }
         paste::paste! {
-            #[doc = stringify!(mono text with $fint_size px size and dark color font)]
+            #[doc = concat!("mono text with ", stringify!($font_size), " px size, theme foreground color and Code role")]
             pub fn [<mono_text_$name>](content: impl ToString) -> crate::components::Text {
                 crate::components::text()
                     .content(content)
                     .size($font_size)
+                    .size_token(crate::aspects::FontSizeToken::$token)
                     .foreground_color((0, 0, 0, 100))
+                    .foreground_token(crate::aspects::ColorToken::Foreground)
                     .font_default(crate::aspects::DefaultFontFamily::Monospace)
+                    .font_token(crate::aspects::FontToken::Mono)
+                    .role(crate::aspects::TextRole::Code)
             }
         }
     };
 }
 
-h!(9xl, 128);
-h!(8xl, 96);
-h!(7xl, 72);
-h!(6xl, 60);
-h!(5xl, 48);
-h!(4xl, 36);
-h!(3xl, 30);
-h!(2xl, 24);
-h!(xl, 20);
-h!(lg, 18);
-h!(base, 16);
-h!(sm, 14);
-h!(xs, 12);
+h!(9xl, 128, Xl9, H1);
+h!(8xl, 96, Xl8, H1);
+h!(7xl, 72, Xl7, H1);
+h!(6xl, 60, Xl6, H1);
+h!(5xl, 48, Xl5, H1);
+h!(4xl, 36, Xl4, H1);
+h!(3xl, 30, Xl3, H2);
+h!(2xl, 24, Xl2, H3);
+h!(xl, 20, Xl, H4);
+h!(lg, 18, Lg, H5);
+h!(base, 16, Base, Paragraph);
+h!(sm, 14, Sm, Paragraph);
+h!(xs, 12, Xs, Paragraph);
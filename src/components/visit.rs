@@ -0,0 +1,311 @@
+//! Traversal and mutation of component trees
+//!
+//! Components are visited in pre-order (a container before its children).
+//! [Custom] components are transparent: their rendered tree is visited instead,
+//! mutable traversals replace them with the rendered tree.
+//!
+//! # Example
+//! ```rust
+//! use zen_rs::{
+//!     components::{github::github_outlined, text, Components},
+//!     dynamic_stack::vstack,
+//! };
+//!
+//! let page: Components = vstack((
+//!     github_outlined(),
+//!     text().content("zen-rs").link("https://github.com"),
+//!     text().content("docs"),
+//! ))
+//! .into();
+//! assert_eq!(page.count_nodes(), 4);
+//!
+//! // recolor all links
+//! let page = page.map_text(|x| match x.get_link() {
+//!     Some(_) => x.foreground_color((37, 99, 235, 100)),
+//!     None => x,
+//! });
+//!
+//! // strip icons for plain-text export
+//! let mut plain = page.clone();
+//! plain.retain(|x| !matches!(x, Components::Icon(_)));
+//! assert_eq!(plain.count_nodes(), 3);
+//! ```
+
+use super::{Components, Container, Custom, Icon, Text};
+
+/// Visitor of a component tree, see [Components::accept].
+pub trait Visitor {
+    /// Called for every container, before its children.
+    fn visit_container(&mut self, _container: &Container) {}
+    /// Called for every text.
+    fn visit_text(&mut self, _text: &Text) {}
+    /// Called for every icon.
+    fn visit_icon(&mut self, _icon: &Icon) {}
+}
+
+/// Mutable visitor of a component tree, see [Components::accept_mut].
+pub trait VisitorMut {
+    /// Called for every container, before its children.
+    fn visit_container(&mut self, _container: &mut Container) {}
+    /// Called for every text.
+    fn visit_text(&mut self, _text: &mut Text) {}
+    /// Called for every icon.
+    fn visit_icon(&mut self, _icon: &mut Icon) {}
+}
+
+impl Components {
+    /// Calls `f` for every component of the tree.
+    pub fn walk(&self, mut f: impl FnMut(&Components)) {
+        fn walk(component: &Components, f: &mut dyn FnMut(&Components)) {
+            match component {
+                Components::Custom(custom) => walk(&custom.render(), f),
+                Components::Container(container) => {
+                    f(component);
+                    for component in container.get_components() {
+                        walk(component, f);
+                    }
+                }
+                Components::Text(_) | Components::Icon(_) => f(component),
+            }
+        }
+        walk(self, &mut f)
+    }
+
+    /// Calls `f` for every component of the tree, allowing modification.
+    ///
+    /// Children of a container are visited after `f` modified the container.
+    pub fn walk_mut(&mut self, mut f: impl FnMut(&mut Components)) {
+        fn walk(component: &mut Components, f: &mut dyn FnMut(&mut Components)) {
+            expand(component);
+            f(component);
+            // `f` may have replaced the component with a custom one
+            expand(component);
+            if let Components::Container(container) = component {
+                for component in container.get_components_mut() {
+                    walk(component, f);
+                }
+            }
+        }
+        walk(self, &mut f)
+    }
+
+    /// Replaces every custom component of the tree with its rendered tree,
+    /// rendering each of them once.
+    pub fn expand(mut self) -> Components {
+        self.walk_mut(|_| {});
+        self
+    }
+
+    /// Passes every component of the tree to the visitor.
+    pub fn accept(&self, visitor: &mut impl Visitor) {
+        self.walk(|component| match component {
+            Components::Container(container) => visitor.visit_container(container),
+            Components::Text(text) => visitor.visit_text(text),
+            Components::Icon(icon) => visitor.visit_icon(icon),
+            Components::Custom(_) => {}
+        })
+    }
+
+    /// Passes every component of the tree to the visitor, allowing modification.
+    pub fn accept_mut(&mut self, visitor: &mut impl VisitorMut) {
+        self.walk_mut(|component| match component {
+            Components::Container(container) => visitor.visit_container(container),
+            Components::Text(text) => visitor.visit_text(text),
+            Components::Icon(icon) => visitor.visit_icon(icon),
+            Components::Custom(_) => {}
+        })
+    }
+
+    /// Folds every component of the tree into an accumulator.
+    pub fn fold<B>(&self, init: B, mut f: impl FnMut(B, &Components) -> B) -> B {
+        fn fold<B>(component: &Components, acc: B, f: &mut dyn FnMut(B, &Components) -> B) -> B {
+            match component {
+                Components::Custom(custom) => fold(&custom.render(), acc, f),
+                Components::Container(container) => {
+                    let acc = f(acc, component);
+                    container
+                        .get_components()
+                        .iter()
+                        .fold(acc, |acc, component| fold(component, acc, f))
+                }
+                Components::Text(_) | Components::Icon(_) => f(acc, component),
+            }
+        }
+        fold(self, init, &mut f)
+    }
+
+    /// Returns the first component of the tree matching the predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&Components) -> bool) -> Option<Components> {
+        let mut out = None;
+        self.walk(|component| {
+            if out.is_none() && predicate(component) {
+                out = Some(component.clone());
+            }
+        });
+        out
+    }
+
+    /// Returns the component of the tree with the id, see [Container::id].
+    pub fn find_by_id(&self, id: &str) -> Option<Components> {
+        self.find(|component| component.get_id() == Some(id))
+    }
+
+    /// Counts the components of the tree, including the root.
+    pub fn count_nodes(&self) -> usize {
+        self.fold(0, |count, _| count + 1)
+    }
+
+    /// Replaces every text of the tree with the result of `f`.
+    pub fn map_text(mut self, mut f: impl FnMut(Text) -> Text) -> Components {
+        self.walk_mut(|component| {
+            if let Components::Text(text) = component {
+                *text = f(std::mem::take(text));
+            }
+        });
+        self
+    }
+
+    /// Replaces every icon of the tree with the result of `f`.
+    pub fn map_icon(mut self, mut f: impl FnMut(Icon) -> Icon) -> Components {
+        self.walk_mut(|component| {
+            if let Components::Icon(icon) = component {
+                *icon = f(std::mem::take(icon));
+            }
+        });
+        self
+    }
+
+    /// Removes every component (with its children) not matching the predicate
+    /// from the containers of the tree, the root is always kept.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&Components) -> bool) {
+        self.walk_mut(|component| {
+            if let Components::Container(container) = component {
+                container.get_components_mut().retain_mut(|x| {
+                    expand(x);
+                    predicate(x)
+                });
+            }
+        })
+    }
+}
+
+/// Replaces a custom component with its rendered tree.
+fn expand(component: &mut Components) {
+    while let Components::Custom(custom) = component {
+        *component = Custom::render(custom);
+    }
+}
+
+#[cfg(test)]
+mod test_visit {
+    use crate::{
+        components::{github::github_outlined, icon, text, Component, Components, Icon, Text},
+        dynamic_stack::{hstack, vstack},
+    };
+
+    use super::{Visitor, VisitorMut};
+
+    #[derive(Debug)]
+    struct Card;
+
+    impl Component for Card {
+        fn render(&self) -> Components {
+            hstack((icon(), text().content("card"))).into()
+        }
+    }
+
+    fn tree() -> Components {
+        vstack((text().content("a"), Card, vstack((github_outlined(),)))).into()
+    }
+
+    #[test]
+    fn walk() {
+        assert_eq!(tree().count_nodes(), 7);
+        let texts = tree().fold(vec![], |mut out, x| {
+            if let Components::Text(text) = x {
+                out.push(text.get_content().to_string());
+            }
+            out
+        });
+        assert_eq!(texts, ["a", "card"]);
+        let found = tree().find(|x| matches!(x, Components::Text(x) if x.get_content() == "card"));
+        assert_eq!(found, Some(text().content("card").into()));
+        assert_eq!(tree().find(|_| false), None);
+        let tree: Components = vstack((text().id("a"), vstack((icon().id("b"),)))).into();
+        assert_eq!(tree.find_by_id("b"), Some(icon().id("b").into()));
+        assert_eq!(tree.find_by_id("c"), None);
+    }
+
+    #[test]
+    fn visitor() {
+        #[derive(Default)]
+        struct Count(usize, usize, usize);
+        impl Visitor for Count {
+            fn visit_container(&mut self, _: &crate::components::Container) {
+                self.0 += 1;
+            }
+            fn visit_text(&mut self, _: &Text) {
+                self.1 += 1;
+            }
+            fn visit_icon(&mut self, _: &Icon) {
+                self.2 += 1;
+            }
+        }
+        let mut count = Count::default();
+        tree().accept(&mut count);
+        assert_eq!((count.0, count.1, count.2), (3, 2, 2));
+
+        struct Upper;
+        impl VisitorMut for Upper {
+            fn visit_text(&mut self, text: &mut Text) {
+                *text = text.clone().content(text.get_content().to_uppercase());
+            }
+        }
+        let mut tree = tree();
+        tree.accept_mut(&mut Upper);
+        assert!(tree
+            .find(|x| matches!(x, Components::Text(x) if x.get_content() == "CARD"))
+            .is_some());
+        // custom components are expanded
+        let Components::Container(root) = tree else {
+            panic!("root is a container")
+        };
+        assert!(matches!(root.get_components()[1], Components::Container(_)));
+    }
+
+    #[test]
+    fn expand() {
+        let card = Components::from(Card);
+        assert_eq!(card, card.clone());
+        assert_eq!(card, Components::from(Card));
+        assert_eq!(
+            card.partial_cmp(&Components::from(Card)),
+            Some(std::cmp::Ordering::Equal)
+        );
+        assert_eq!(card.clone().expand(), Components::from(Card).expand());
+        assert_eq!(tree().expand().count_nodes(), 7);
+        assert!(tree()
+            .expand()
+            .find(|x| matches!(x, Components::Custom(_)))
+            .is_none());
+    }
+
+    #[test]
+    fn mutate() {
+        let tree = tree().map_text(|x| x.size(99));
+        assert_eq!(
+            tree.fold(0, |n, x| match x {
+                Components::Text(x) if x.get_size() == 99 => n + 1,
+                _ => n,
+            }),
+            2
+        );
+        let tree = tree.map_icon(|x| x.size(8));
+        assert!(tree
+            .find(|x| matches!(x, Components::Icon(x) if x.get_width() != 8))
+            .is_none());
+        let mut plain = tree;
+        plain.retain(|x| !matches!(x, Components::Icon(_)));
+        assert_eq!(plain.count_nodes(), 5);
+    }
+}
@@ -7,56 +7,197 @@ use crate::{
 /// Default container
 ///
 /// Container with [Order::TopToBottom]
+///
+/// Children are any [Dynamic], e.g. a tuple of components. Common iterator
+/// adaptors (`map`, `filter`, ...) are accepted as is, wrap any other
+/// iterator in [each].
 #[inline]
 pub fn vstack<D: Dynamic>(vstack: D) -> Container {
     let mut container = container().direction(Order::TopToBottom);
     let mut push_fn = |x: &Components| {
         container.push(x);
     };
-    vstack.push(&mut push_fn);
+    vstack.push_components(&mut push_fn);
     container
 }
 /// Container with [Order::BottomToTop]
+///
+/// Children are any [Dynamic], see [vstack].
 #[inline]
 pub fn rvstack<D: Dynamic>(vstack: D) -> Container {
     let mut container = container().direction(Order::BottomToTop);
     let mut push_fn = |x: &Components| {
         container.push(x);
     };
-    vstack.push(&mut push_fn);
+    vstack.push_components(&mut push_fn);
     container
 }
 /// Container with [Order::LefToRight]
+///
+/// Children are any [Dynamic], see [vstack].
 #[inline]
 pub fn hstack<D: Dynamic>(vstack: D) -> Container {
     let mut container = container().direction(Order::LefToRight);
     let mut push_fn = |x: &Components| {
         container.push(x);
     };
-    vstack.push(&mut push_fn);
+    vstack.push_components(&mut push_fn);
     container
 }
 /// Container with [Order::RightToLeft]
+///
+/// Children are any [Dynamic], see [vstack].
 #[inline]
 pub fn rhstack<D: Dynamic>(vstack: D) -> Container {
     let mut container = container().direction(Order::RightToLeft);
     let mut push_fn = |x: &Components| {
         container.push(x);
     };
-    vstack.push(&mut push_fn);
+    vstack.push_components(&mut push_fn);
     container
 }
 
+/// Children of a stack
+///
+/// Implemented for:
+/// - anything convertible into [Components] (a single child)
+/// - tuples, [Vec], arrays and [Option] of [Dynamic]
+/// - common iterator adaptors over [Dynamic] (e.g. `repos.iter().map(card)`)
+/// - any other iterator over [Dynamic] wrapped in [each]
+///
+/// ```rust
+/// use zen_rs::{
+///     components::text,
+///     dynamic_stack::{each, vstack},
+/// };
+///
+/// let repos = ["zen-rs", "rui"];
+/// let is_logged_in = false;
+/// let page = vstack((
+///     text().content("Repositories"),
+///     repos.iter().map(|x| text().content(x)),
+///     each(repos.iter().peekable().map(|x| text().content(x))),
+///     is_logged_in.then(|| text().content("Log out")),
+///     (vec![text(), text()], [text()]),
+/// ));
+/// assert_eq!(page.get_components().len(), 8);
+/// ```
 pub trait Dynamic {
-    fn push<F: FnMut(&Components)>(self, f: &mut F);
+    /// Passes every child to `f`, in order.
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F);
+}
+
+/// Collects [Dynamic] children, e.g. to unify branches of different types
+#[inline]
+pub fn components<D: Dynamic>(dynamic: D) -> Vec<Components> {
+    let mut out = vec![];
+    dynamic.push_components(&mut |x: &Components| out.push(x.clone()));
+    out
+}
+
+impl Dynamic for () {
+    fn push_components<F: FnMut(&Components)>(self, _: &mut F) {}
+}
+
+impl<T: Into<Components>> Dynamic for T {
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+        f(&self.into());
+    }
+}
+
+impl<T: Dynamic> Dynamic for Vec<T> {
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+        for x in self {
+            x.push_components(f);
+        }
+    }
+}
+
+impl<T: Dynamic, const N: usize> Dynamic for [T; N] {
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+        for x in self {
+            x.push_components(f);
+        }
+    }
+}
+
+impl<T: Dynamic> Dynamic for Option<T> {
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+        if let Some(x) = self {
+            x.push_components(f);
+        }
+    }
+}
+
+/// Wraps an iterator over [Dynamic] children, see [each]
+#[derive(Debug, Clone)]
+pub struct Iter<I>(pub I);
+
+/// Children from any iterator over [Dynamic], e.g. `each(groups.iter().flat_map(cards))`
+///
+/// Common iterator adaptors are [Dynamic] as is, a blanket implementation
+/// for every iterator would overlap with the types convertible into [Components].
+#[inline]
+pub fn each<I: IntoIterator>(iter: I) -> Iter<I>
+where
+    I::Item: Dynamic,
+{
+    Iter(iter)
+}
+
+impl<I: IntoIterator> Dynamic for Iter<I>
+where
+    I::Item: Dynamic,
+{
+    fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+        for x in self.0 {
+            x.push_components(f);
+        }
+    }
+}
+
+macro_rules! MakeDynamicIter {
+    ($($iter:ty => <$($generic:ident),*>),* $(,)?) => {
+        $(
+            impl<$($generic),*> Dynamic for $iter
+            where
+                $iter: Iterator,
+                <$iter as Iterator>::Item: Dynamic,
+            {
+                fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
+                    for x in self {
+                        x.push_components(f);
+                    }
+                }
+            }
+        )*
+    }
+}
+
+MakeDynamicIter! {
+    std::iter::Map<I, P> => <I, P>,
+    std::iter::Filter<I, P> => <I, P>,
+    std::iter::FilterMap<I, P> => <I, P>,
+    std::iter::Chain<A, B> => <A, B>,
+    std::iter::Take<I> => <I>,
+    std::iter::Skip<I> => <I>,
+    std::iter::TakeWhile<I, P> => <I, P>,
+    std::iter::SkipWhile<I, P> => <I, P>,
+    std::iter::StepBy<I> => <I>,
+    std::iter::Rev<I> => <I>,
+    std::iter::Cloned<I> => <I>,
+    std::iter::Inspect<I, P> => <I, P>,
+    std::iter::Once<T> => <T>,
+    std::iter::FromFn<P> => <P>,
+    std::vec::IntoIter<T> => <T>,
 }
 
 macro_rules! MakeDynamic {
     ($($dynamic:ident),* | $($id:tt),*) => {
-        impl< $( $dynamic:  Into<Components>, )* > Dynamic for ( $( $dynamic, )* ) {
-            fn push<F: FnMut(&Components)>(self, f: &mut F) {
+        impl< $( $dynamic: Dynamic, )* > Dynamic for ( $( $dynamic, )* ) {
+            fn push_components<F: FnMut(&Components)>(self, f: &mut F) {
                 $(
-                    f(&self.$id.into());
+                    self.$id.push_components(f);
                 )*
             }
         }
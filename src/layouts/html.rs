@@ -9,15 +9,31 @@
 //! The components are modular, and the builder pattern is used to allow for
 //! easy chaining of method calls to configure and generate the final output.
 
-use crate::components::Components;
+use crate::{
+    aspects::{ColorScheme, FontFace},
+    components::Components,
+    theme::Theme,
+};
 
+mod attributes;
 mod container;
+mod document;
+mod font;
+mod format;
 mod icon;
+mod social;
 mod text;
+mod theme;
 
+pub use attributes::*;
 pub use container::*;
+pub use document::*;
+pub use font::*;
+pub use format::*;
 pub use icon::*;
+pub use social::*;
 pub use text::*;
+pub use theme::*;
 
 /// Creates a new `HtmlBuilder` with default values.
 pub fn html_builder() -> HtmlBuilder {
@@ -35,6 +51,42 @@ pub struct HtmlBuilder {
     /// Example:
     /// - @import url('<https://fonts.googleapis.com/css2?family=Doto:wght@100..900&display=swap>');
     css_font_import_urls: String,
+    /// Font files rendered as `@font-face` rules.
+    ///
+    /// The family name of each face can be used with [crate::components::Text::font_custom].
+    font_faces: Vec<FontFace>,
+    /// Theme which resolves the design tokens of the components.
+    theme: Theme,
+    /// Theme used for the dark color scheme.
+    dark_theme: Option<Theme>,
+    /// Forced color scheme, `None` follows `prefers-color-scheme` of the viewer.
+    color_scheme: Option<ColorScheme>,
+    /// Renders icons as references into a sprite sheet of `<symbol>`s.
+    icon_sprites: bool,
+    /// Doctype of the document, `None` for `html`.
+    doctype: Option<String>,
+    /// Character encoding of the document, `None` for `utf-8`.
+    charset: Option<String>,
+    /// Language of the document.
+    lang: Option<String>,
+    /// Base direction of the document text.
+    dir: Option<Dir>,
+    /// Title of the document.
+    title: Option<String>,
+    /// Description of the document.
+    description: Option<String>,
+    /// Viewport of the document, `None` for [DEFAULT_VIEWPORT].
+    viewport: Option<String>,
+    /// URL of the favicon.
+    favicon: Option<String>,
+    /// Meta tags as (name, content).
+    metas: Vec<(String, String)>,
+    /// Link tags as (rel, href).
+    links: Vec<(String, String)>,
+    /// Open Graph and Twitter meta.
+    social: Option<SocialMeta>,
+    /// Formatting of the output.
+    format: HtmlFormat,
     /// The main component to be rendered.
     component: Components,
 }
@@ -52,24 +104,183 @@ impl HtmlBuilder {
         self
     }
 
+    /// Registers a font file as `@font-face` rule.
+    ///
+    /// # Example
+    /// ```rust
+    /// use zen_rs::{aspects::font_url, components::text, layouts::html::html_builder};
+    ///
+    /// let inter = font_url("Inter", "fonts/inter.woff2");
+    /// let html = html_builder()
+    ///     .component(text().content("Hi").font_custom(inter.get_family()))
+    ///     .font_face(inter)
+    ///     .build_as_html();
+    /// assert!(html.contains("@font-face { font-family: 'Inter';"));
+    /// ```
+    pub fn font_face(mut self, font_face: FontFace) -> Self {
+        self.font_faces.push(font_face);
+        self
+    }
+
+    /// Registers multiple font files as `@font-face` rules.
+    pub fn font_faces(mut self, font_faces: impl IntoIterator<Item = FontFace>) -> Self {
+        self.font_faces.extend(font_faces);
+        self
+    }
+
+    /// Gets the registered font files.
+    pub fn get_font_faces(&self) -> &[FontFace] {
+        &self.font_faces
+    }
+
+    /// Builds the `@font-face` rules of the registered font files.
+    pub fn build_font_faces(&self) -> String {
+        self.get_font_faces()
+            .iter()
+            .map(font_face_css)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Sets the theme which resolves the design tokens of the components.
+    ///
+    /// Switching the theme restyles every component which uses tokens.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Gets the theme.
+    pub fn get_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the theme used for the dark color scheme.
+    ///
+    /// Without forced [HtmlBuilder::color_scheme] it's applied by
+    /// `@media (prefers-color-scheme: dark)`.
+    pub fn dark_theme(mut self, dark_theme: Theme) -> Self {
+        self.dark_theme = Some(dark_theme);
+        self
+    }
+
+    /// Gets the theme used for the dark color scheme.
+    pub fn get_dark_theme(&self) -> Option<&Theme> {
+        self.dark_theme.as_ref()
+    }
+
+    /// Forces the color scheme of the output instead of following the viewer preference.
+    pub fn color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = Some(color_scheme);
+        self
+    }
+
+    /// Gets the forced color scheme.
+    pub fn get_color_scheme(&self) -> Option<ColorScheme> {
+        self.color_scheme
+    }
+
+    /// Builds the variables of the theme for the light and the dark color scheme,
+    /// empty when the tree uses no tokens or dark colors.
+    ///
+    /// - [ColorScheme::Light] renders only the light theme
+    /// - [ColorScheme::Dark] renders the dark theme and dark colors over the light one
+    fn theme_scheme_variables(&self) -> (String, String) {
+        let tokens = uses_tokens(self.get_component());
+        let light = if tokens {
+            theme_variables(self.get_theme())
+        } else {
+            "".to_string()
+        };
+        let dark = match self.get_color_scheme() {
+            Some(ColorScheme::Light) => vec![],
+            _ => {
+                let theme = self.get_dark_theme().filter(|_| tokens);
+                let colors = dark_color_variables(&dark_colors(self.get_component()));
+                theme
+                    .map(theme_variables)
+                    .into_iter()
+                    .chain(Some(colors).filter(|x| !x.is_empty()))
+                    .collect()
+            }
+        };
+        (light, dark.join(" "))
+    }
+
+    /// Gets the class of the rendered root which scopes the theme variables,
+    /// `None` when the tree uses no tokens or dark colors.
+    ///
+    /// The class is a hash of the variables, so outputs with different themes
+    /// get different classes.
+    pub fn get_theme_scope(&self) -> Option<String> {
+        let (light, dark) = self.theme_scheme_variables();
+        if light.is_empty() && dark.is_empty() {
+            return None;
+        }
+        let scheme = self.get_color_scheme();
+        Some(format!(
+            "zen-theme-{:016x}",
+            fnv1a(&format!("{light}|{dark}|{scheme:?}"))
+        ))
+    }
+
+    /// Builds the theme rules, including dark variants of colors.
+    ///
+    /// Rules are scoped to the rendered root, see [HtmlBuilder::get_theme_scope].
+    ///
+    /// - without forced color scheme dark rules are wrapped into `@media (prefers-color-scheme: dark)`
+    /// - [ColorScheme::Light] renders only the light theme
+    /// - [ColorScheme::Dark] renders the dark theme and dark colors over the light one
+    pub fn build_theme(&self) -> String {
+        let Some(scope) = self.get_theme_scope() else {
+            return "".to_string();
+        };
+        let (light, dark) = self.theme_scheme_variables();
+        match self.get_color_scheme() {
+            Some(ColorScheme::Dark) => {
+                let variables = [light, dark].into_iter().filter(|x| !x.is_empty());
+                format!(".{scope} {{ {} }}", variables.collect::<Vec<_>>().join(" "))
+            }
+            _ => {
+                let mut out = vec![];
+                if !light.is_empty() {
+                    out.push(format!(".{scope} {{ {light} }}"));
+                }
+                if !dark.is_empty() {
+                    out.push(format!(
+                        "@media (prefers-color-scheme: dark) {{ .{scope} {{ {dark} }} }}"
+                    ));
+                }
+                out.join(" ")
+            }
+        }
+    }
+
     /// Returns a default CSS reset to disable browser default styling.
     pub fn css_disable_default_browser_css(&self) -> &str {
-        "* { margin: 0; padding: 0; box-sizing: border-box; overflow: clip; } html, body { height: 100%; line-height: 1.5; } body { background: none; color: inherit; text-align: inherit; } h1, h2, h3, h4, h5, h6 { font-size: inherit; font-weight: inherit; margin: 0; } p { margin: 0; } ul, ol { list-style: none; } a { text-decoration: none; color: inherit; } "
+        "* { margin: 0; padding: 0; box-sizing: border-box; } html, body { height: 100%; line-height: 1.5; } body { background: none; color: inherit; text-align: inherit; } h1, h2, h3, h4, h5, h6 { font-size: inherit; font-weight: inherit; margin: 0; } p { margin: 0; } ul, ol { list-style: none; } a { text-decoration: none; color: inherit; } "
     }
 
-    /// Builds the full CSS style, including any custom font imports.
+    /// Builds the full CSS style, including any custom font imports and font faces.
+    ///
+    /// **note** font imports go first, since `@import` is ignored after any other rule
     pub fn build_style(&self) -> String {
         let css_font_import_urls = self.get_css_font_import_urls();
+        let font_faces = self.build_font_faces();
+        let theme = self.build_theme();
         let disable_default = self.css_disable_default_browser_css();
-        format!(r#"<style>{disable_default}{css_font_import_urls}</style>"#)
+        format!(r#"<style>{css_font_import_urls}{font_faces}{theme}{disable_default}</style>"#)
     }
 }
 
 // Component-related methods
 impl HtmlBuilder {
     /// Sets the component to be rendered.
+    ///
+    /// Custom components are expanded here (see [Components::expand]),
+    /// so the passes of a build don't render them again.
     pub fn component(mut self, component: impl Into<Components>) -> Self {
-        self.component = component.into();
+        self.component = component.into().expand();
         self
     }
 
@@ -77,6 +288,57 @@ impl HtmlBuilder {
     pub fn get_component(&self) -> &Components {
         &self.component
     }
+
+    /// Renders icons as `<use href="#id">` of a hidden sprite sheet.
+    ///
+    /// Identical icons (same content and viewBox) share one `<symbol>`,
+    /// which shrinks documents with repeated icons. Symbol ids are a hash of
+    /// the symbol (`zen-icon-<hash>`), see [IconSprite].
+    ///
+    /// # Example
+    /// ```rust
+    /// use zen_rs::{
+    ///     components::github::github_outlined, dynamic_stack::hstack, layouts::html::html_builder,
+    /// };
+    ///
+    /// let html = html_builder()
+    ///     .component(hstack((github_outlined(), github_outlined().size(24))))
+    ///     .icon_sprites(true)
+    ///     .build_as_html();
+    /// assert_eq!(html.matches("<symbol").count(), 1);
+    /// assert_eq!(html.matches("<use").count(), 2);
+    /// ```
+    pub fn icon_sprites(mut self, icon_sprites: bool) -> Self {
+        self.icon_sprites = icon_sprites;
+        self
+    }
+
+    /// Gets whether icons are rendered with a sprite sheet.
+    pub fn get_icon_sprites(&self) -> bool {
+        self.icon_sprites
+    }
+
+    /// Sets the formatting of [HtmlBuilder::build] and [HtmlBuilder::build_as_html].
+    ///
+    /// # Example
+    /// ```rust
+    /// use zen_rs::{components::text, layouts::html::{html_builder, HtmlFormat}};
+    ///
+    /// let html = html_builder()
+    ///     .component(text().content("Hi"))
+    ///     .format(HtmlFormat::Pretty)
+    ///     .build_as_html();
+    /// assert!(html.starts_with("<!DOCTYPE html>\n<html>\n  <head>\n"));
+    /// ```
+    pub fn format(mut self, format: HtmlFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Gets the formatting of the output.
+    pub fn get_format(&self) -> HtmlFormat {
+        self.format
+    }
 }
 
 // Methods for building HTML output
@@ -84,35 +346,86 @@ impl HtmlBuilder {
     /// Converts a given component to HTML.
     ///
     /// This function matches the component type and calls the respective
-    /// rendering function for `Container`, `Text`, or `Icon` components.
+    /// rendering function for `Container`, `Text`, or `Icon` components,
+    /// `Custom` components are rendered into their tree first.
     pub fn render_component(component: &Components) -> String {
         match component {
             Components::Container(component) => container_html(component),
             Components::Text(component) => text_html(component),
             Components::Icon(component) => icon_html(component),
+            Components::Custom(component) => Self::render_component(&component.render()),
+        }
+    }
+
+    /// Converts a given component to HTML, rendering icons as references
+    /// into the sprite sheet (see [HtmlBuilder::icon_sprites]).
+    pub fn render_component_with_sprites(component: &Components, sprite: &IconSprite) -> String {
+        match component {
+            Components::Container(component) => container_html_with(component, &|x| {
+                Self::render_component_with_sprites(x, sprite)
+            }),
+            Components::Text(component) => text_html(component),
+            Components::Icon(component) => icon_use_html(component, sprite),
+            Components::Custom(component) => {
+                Self::render_component_with_sprites(&component.render(), sprite)
+            }
         }
     }
 
     /// Renders the current component to HTML.
+    ///
+    /// The root gets the class of [HtmlBuilder::get_theme_scope].
     pub fn render(&self) -> String {
-        let component = self.get_component();
-        Self::render_component(component)
+        let scoped = self
+            .get_theme_scope()
+            .map(|scope| with_class(self.get_component().clone(), scope));
+        let component = scoped.as_ref().unwrap_or(self.get_component());
+        if self.get_icon_sprites() {
+            Self::render_component_with_sprites(component, &icon_sprite(component))
+        } else {
+            Self::render_component(component)
+        }
+    }
+
+    /// Builds the hidden sprite sheet, empty without [HtmlBuilder::icon_sprites].
+    pub fn build_sprite(&self) -> String {
+        if self.get_icon_sprites() {
+            icon_sprite_html(self.get_component())
+        } else {
+            "".to_string()
+        }
     }
 
     /// Renders the current component along with the CSS styles as a complete HTML.
     pub fn build(&self) -> String {
+        let sprite = self.build_sprite();
         let render = self.render();
         let style = self.build_style();
-        format!(
-            r#"{render}
-            {style}"#
-        )
+        format_html(&format!("{sprite}{render}{style}"), self.get_format())
     }
 
     /// Generates a complete HTML document, including the head and body sections.
     pub fn build_as_html(&self) -> String {
+        let doctype = self.build_doctype();
+        let html_attributes = self.build_html_attributes();
+        let head = self.build_head();
+        let social = self.build_social();
+        let sprite = self.build_sprite();
         let render = self.render();
         let style = self.build_style();
-        format!(r#"<html><head>{style}</head><body>{render}</body></html>"#)
+        let html = format!(
+            r#"{doctype}<html{html_attributes}><head>{head}{social}{style}</head><body>{sprite}{render}</body></html>"#
+        );
+        format_html(&html, self.get_format())
+    }
+}
+
+/// Adds a class to the root of a component tree.
+fn with_class(component: Components, class: String) -> Components {
+    match component {
+        Components::Container(container) => container.class(class).into(),
+        Components::Text(text) => text.class(class).into(),
+        Components::Icon(icon) => icon.class(class).into(),
+        Components::Custom(custom) => with_class(custom.render(), class),
     }
 }
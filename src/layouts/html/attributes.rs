@@ -0,0 +1,85 @@
+//! This module contains the rendering of the `id`, `class` and custom
+//! (`data-*`, `aria-*`) attributes shared by every component.
+
+use crate::aspects::Attributes;
+
+/// Escapes a value for a double quoted HTML attribute.
+pub(crate) fn escape_attribute(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for x in value.chars() {
+        match x {
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            x => out.push(x),
+        }
+    }
+    out
+}
+
+/// 64-bit FNV-1a hash, stable across builds and toolchains, for generated names.
+pub(crate) fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, x| {
+        (hash ^ x as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Renders `id`, `class` and custom attributes, each one prefixed with a space.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::{components::text, layouts::html::attributes_html};
+///
+/// let text = text().id("title").class("card big").data("repo", "zen-rs");
+/// assert_eq!(
+///     attributes_html(text.get_id(), text.get_classes(), text.get_attributes()),
+///     r#" id="title" class="card big" data-repo="zen-rs""#
+/// );
+/// ```
+pub fn attributes_html(id: Option<&str>, classes: &[String], attributes: &Attributes) -> String {
+    let mut out = String::new();
+    if let Some(id) = id {
+        out.push_str(&format!(r#" id="{}""#, escape_attribute(id)));
+    }
+    if !classes.is_empty() {
+        out.push_str(&format!(
+            r#" class="{}""#,
+            escape_attribute(&classes.join(" "))
+        ));
+    }
+    for (name, value) in attributes {
+        out.push_str(&format!(r#" {name}="{}""#, escape_attribute(value)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_attributes {
+    use crate::{
+        components::{container, icon, text},
+        layouts::html::{container_html, icon_html, text_html},
+    };
+
+    #[test]
+    fn components() {
+        let html = container_html(&container().id("root").aria("label", "Repos"));
+        assert!(html.starts_with("<div style="));
+        assert!(html.contains(r#" id="root" aria-label="Repos">"#));
+
+        let html = text_html(&text().link("/").class("link").data("x", r#"a "b" <c>"#));
+        assert!(html.contains(r#" class="link" data-x="a &quot;b&quot; &lt;c&gt;">"#));
+
+        let html = icon_html(&icon().id("logo").classes(["a", "b"]));
+        assert!(html.contains(r#"id="logo" class="a b""#));
+
+        assert!(!text_html(&text()).contains("class="));
+    }
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(super::fnv1a(""), 0xcbf29ce484222325);
+        assert_eq!(super::fnv1a("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(super::fnv1a("foobar"), 0x85944171f73967e8);
+    }
+}
@@ -3,12 +3,51 @@
 //!
 //! The `container_html` function takes a `Container` component, extracts its properties
 //! (such as background color, size, border, gap, padding, alignment, and direction),
-//! and generates an HTML `<div>` element. The function applies appropriate CSS styles
+//! and generates an HTML element of its role (`<div>` by default). The function applies appropriate CSS styles
 //! for the container layout (including flexbox styles) and recursively renders
 //! its child components into the container.
 
-use super::HtmlBuilder;
-use crate::components::container::Container;
+use super::{attributes_html, color_value, radius_value, space_value, HtmlBuilder};
+use crate::{
+    aspects::{Align, ContainerRole, Justify},
+    components::{container::Container, Components},
+};
+
+/// Maps [Justify] onto the CSS `justify-content`/`align-content` value.
+pub(crate) fn justify_css(justify: &Justify) -> &'static str {
+    match justify {
+        Justify::Start => "flex-start",
+        Justify::Center => "center",
+        Justify::End => "flex-end",
+        Justify::SpaceBetween => "space-between",
+        Justify::SpaceAround => "space-around",
+        Justify::SpaceEvenly => "space-evenly",
+    }
+}
+
+/// Maps [ContainerRole] onto the HTML tag.
+pub(crate) fn container_tag(role: &ContainerRole) -> &'static str {
+    match role {
+        ContainerRole::Generic => "div",
+        ContainerRole::Section => "section",
+        ContainerRole::Article => "article",
+        ContainerRole::Header => "header",
+        ContainerRole::Footer => "footer",
+        ContainerRole::Nav => "nav",
+        ContainerRole::Figure => "figure",
+    }
+}
+
+/// Maps [Align] onto the CSS `align-items`/`align-self` value.
+pub(crate) fn align_css(align: &Align) -> &'static str {
+    match align {
+        Align::Start => "flex-start",
+        Align::Center => "center",
+        Align::End => "flex-end",
+        Align::Stretch => "stretch",
+        Align::Baseline => "baseline",
+    }
+}
 
 /// Renders a `Container` component into an HTML string with the specified styles
 /// such as background color, size, border, padding, alignment, and flexbox layout.
@@ -22,24 +61,29 @@ use crate::components::container::Container;
 /// A string representing the HTML `<div>` element with the specified properties,
 /// styles, and content (child components).
 pub fn container_html(component: &Container) -> String {
+    container_html_with(component, &HtmlBuilder::render_component)
+}
+
+/// Renders a `Container` component like [container_html], rendering the child
+/// components with `render`.
+pub(crate) fn container_html_with(
+    component: &Container,
+    render: &dyn Fn(&Components) -> String,
+) -> String {
     // data
-    let (bg_red, bg_green, bg_blue, bg_alpha) = component.get_background_color();
+    let bg = component.get_background_color();
     let w = component.get_width();
     let h = component.get_height();
-    let ((b_size_l, b_size_t, b_size_b, b_size_r), (b_red, b_green, b_blue, b_alpha), b_radius) = component.get_border();
+    let ((b_size_l, b_size_t, b_size_b, b_size_r), b_color, b_radius) = component.get_border();
     let gap = component.get_gap();
     let padding = component.get_padding();
-    let align = component.get_align_content();
-
-    // local state
-    let mut is_col = false;
 
     // content
     let content = {
         let mut out = String::new();
         let components = component.get_components();
         for component in components {
-            let component = HtmlBuilder::render_component(component);
+            let component = render(component);
             out.push_str(&component);
         }
         out
@@ -47,8 +91,28 @@ pub fn container_html(component: &Container) -> String {
 
     // css
 
-    let padding = format!("padding: {padding}px;");
-    let gap = format!("gap: {gap}px;");
+    let padding = format!(
+        "padding: {};",
+        space_value(component.get_padding_token(), *padding)
+    );
+    let gap = {
+        let mut out = format!("gap: {};", space_value(component.get_gap_token(), *gap));
+        if let Some(row_gap) = component.get_row_gap() {
+            out.push_str(&format!(" row-gap: {row_gap}px;"));
+        }
+        if let Some(column_gap) = component.get_column_gap() {
+            out.push_str(&format!(" column-gap: {column_gap}px;"));
+        }
+        out
+    };
+    let wrap = {
+        let wp = match component.get_wrap() {
+            crate::aspects::Wrap::NoWrap => "nowrap",
+            crate::aspects::Wrap::Wrap => "wrap",
+            crate::aspects::Wrap::WrapReverse => "wrap-reverse",
+        };
+        format!("flex-wrap: {wp};")
+    };
     let flex = if *component.get_flex() {
         "display: flex;"
     } else {
@@ -56,41 +120,33 @@ pub fn container_html(component: &Container) -> String {
     };
     let direction = {
         let dn = match component.get_direction() {
-            crate::aspects::Order::TopToBottom => {
-                is_col = !is_col;
-                "column"
-            }
+            crate::aspects::Order::TopToBottom => "column",
             crate::aspects::Order::BottomToTop => "column-reverse",
             crate::aspects::Order::LefToRight => "row",
             crate::aspects::Order::RightToLeft => "row-reverse",
         };
         format!("flex-direction: {dn};")
     };
-    let align_content = {
-        let a = match align {
-            crate::aspects::Align::Left => "flex-left",
-            crate::aspects::Align::Center => "center",
-            crate::aspects::Align::Right => "flex-end",
-            crate::aspects::Align::SpaceBetween => "space-between",
-        };
-        if is_col {
-            format!("align-items: {a};")
-        } else {
-            format!("justify-content: {a};")
-        }
-    };
-    let align_items = {
-        let a = match align {
-            crate::aspects::Align::Left => "flex-left",
-            crate::aspects::Align::Center => "center",
-            crate::aspects::Align::Right => "flex-end",
-            crate::aspects::Align::SpaceBetween => "space-between",
+    let justify_content = format!(
+        "justify-content: {};",
+        justify_css(component.get_justify_content())
+    );
+    let align_items = format!("align-items: {};", align_css(component.get_align_items()));
+    let align_content = component
+        .get_align_content()
+        .map(|x| format!("align-content: {};", justify_css(&x)))
+        .unwrap_or_default();
+    let align_self = component
+        .get_align_self()
+        .map(|x| format!("align-self: {};", align_css(&x)))
+        .unwrap_or_default();
+    let overflow = {
+        let of = match component.get_overflow() {
+            crate::aspects::Overflow::Visible => "visible",
+            crate::aspects::Overflow::Clip => "clip",
+            crate::aspects::Overflow::Hidden => "hidden",
         };
-        if is_col {
-            format!("justify-content: {a};")
-        } else {
-            format!("align-items: {a};")
-        }
+        format!("overflow: {of};")
     };
     let size = {
         let mut out = String::new();
@@ -107,19 +163,162 @@ pub fn container_html(component: &Container) -> String {
 
         out
     };
-    let bg_color = format!("background-color: rgba({bg_red}, {bg_green}, {bg_blue}, {bg_alpha});");
-    let border = format!(
-        "border-left: {b_size_l}px solid rgba({b_red}, {b_green}, {b_blue}, {b_alpha});
-        border-top: {b_size_t}px solid rgba({b_red}, {b_green}, {b_blue}, {b_alpha});
-        border-bottom: {b_size_b}px solid rgba({b_red}, {b_green}, {b_blue}, {b_alpha});
-        border-right: {b_size_r}px solid rgba({b_red}, {b_green}, {b_blue}, {b_alpha});
-        border-radius: {b_radius}px;");
+    let bg_color = format!(
+        "background-color: {};",
+        color_value(
+            component.get_background_token(),
+            bg,
+            component.get_background_color_dark()
+        )
+    );
+    let border = {
+        let b_color = color_value(
+            component.get_border_color_token(),
+            b_color,
+            component.get_border_color_dark(),
+        );
+        let b_radius = radius_value(component.get_border_radius_token(), *b_radius);
+        format!(
+            "border-left: {b_size_l}px solid {b_color}; border-top: {b_size_t}px solid {b_color}; border-bottom: {b_size_b}px solid {b_color}; border-right: {b_size_r}px solid {b_color}; border-radius: {b_radius};"
+        )
+    };
     // css build
     let style = format!(
-        r#"style="{flex} {align_content} {align_items} {size} {gap} {direction} {padding} {bg_color} {border}""#
+        r#"style="{flex} {justify_content} {align_items} {align_content} {align_self} {size} {gap} {direction} {wrap} {overflow} {padding} {bg_color} {border}""#
     );
 
     // out data
-    let out = format!("<div {style}>{content}</div>");
+    let attributes = attributes_html(
+        component.get_id(),
+        component.get_classes(),
+        component.get_attributes(),
+    );
+    let tag = container_tag(&component.get_role());
+    let out = format!("<{tag} {style}{attributes}>{content}</{tag}>");
     out
 }
+
+#[cfg(test)]
+mod test_container {
+    use crate::{
+        aspects::{
+            Align, ColorToken, ContainerRole, Justify, Overflow, RadiusToken, SpaceToken, Wrap,
+        },
+        components::{container, text::text},
+        dynamic_stack::{hstack, vstack},
+    };
+
+    use super::container_html;
+
+    #[test]
+    fn wrap() {
+        let tags = hstack((text().content("rust"), text().content("svg")))
+            .flex()
+            .wrap(Wrap::Wrap)
+            .gap(4);
+        let html = container_html(&tags);
+        assert!(html.contains("flex-wrap: wrap;"));
+        assert!(html.contains("gap: 4px;"));
+        assert!(!html.contains("row-gap"));
+        assert!(!html.contains("column-gap"));
+    }
+
+    #[test]
+    fn row_column_gap() {
+        let tags = hstack((text().content("rust"),))
+            .flex()
+            .wrap(Wrap::WrapReverse)
+            .row_gap(8)
+            .column_gap(2);
+        let html = container_html(&tags);
+        assert!(html.contains("flex-wrap: wrap-reverse;"));
+        assert!(html.contains("row-gap: 8px;"));
+        assert!(html.contains("column-gap: 2px;"));
+    }
+
+    #[test]
+    fn nowrap_default() {
+        let html = container_html(&hstack((text(),)));
+        assert!(html.contains("flex-wrap: nowrap;"));
+    }
+
+    #[test]
+    fn alignment_independent_of_direction() {
+        for stack in [hstack((text(),)), vstack((text(),))] {
+            let html = container_html(
+                &stack
+                    .flex()
+                    .justify_content(Justify::SpaceEvenly)
+                    .align_items(Align::Baseline),
+            );
+            assert!(html.contains("justify-content: space-evenly;"));
+            assert!(html.contains("align-items: baseline;"));
+        }
+    }
+
+    #[test]
+    fn alignment_defaults() {
+        let html = container_html(&vstack((text(),)));
+        assert!(html.contains("justify-content: flex-start;"));
+        assert!(html.contains("align-items: stretch;"));
+        assert!(!html.contains("align-content"));
+        assert!(!html.contains("align-self"));
+        assert!(!html.contains("flex-left"));
+    }
+
+    #[test]
+    fn align_content_and_self() {
+        let html = container_html(
+            &hstack((vstack((text(),)).align_self(Align::End),))
+                .wrap(Wrap::Wrap)
+                .align_content(Justify::SpaceBetween),
+        );
+        assert!(html.contains("align-content: space-between;"));
+        assert!(html.contains("align-self: flex-end;"));
+    }
+
+    #[test]
+    fn overflow() {
+        let html = container_html(&vstack((text(),)));
+        assert!(html.contains("overflow: visible;"));
+        let html = container_html(&vstack((text(),)).overflow(Overflow::Hidden));
+        assert!(html.contains("overflow: hidden;"));
+    }
+
+    #[test]
+    fn tokens() {
+        let html = container_html(
+            &vstack((text(),))
+                .background_token(ColorToken::Surface)
+                .border_color_token(ColorToken::Border)
+                .border_radius_token(RadiusToken::Lg)
+                .gap_token(SpaceToken::Sm)
+                .padding_token(SpaceToken::Md),
+        );
+        assert!(html.contains("background-color: var(--zen-color-surface, rgba(0, 0, 0, 0));"));
+        assert!(html.contains("border-top: 0px solid var(--zen-color-border, rgba(0, 0, 0, 0));"));
+        assert!(html.contains("border-radius: var(--zen-radius-lg, 0px);"));
+        assert!(html.contains("gap: var(--zen-space-sm, 0px);"));
+        assert!(html.contains("padding: var(--zen-space-md, 0px);"));
+        let html = container_html(
+            &vstack((text(),))
+                .background_token(ColorToken::Surface)
+                .gap_token(SpaceToken::Sm)
+                .padding_token(SpaceToken::Md)
+                .border_radius_token(RadiusToken::Lg)
+                .background_color((1, 2, 3, 100))
+                .gap(3)
+                .padding(5)
+                .border_radius(7),
+        );
+        assert!(!html.contains("var(--zen-"));
+        assert!(html.contains("gap: 3px;") && html.contains("border-radius: 7px;"));
+    }
+
+    #[test]
+    fn role() {
+        assert!(container_html(&container()).starts_with("<div "));
+        let html = container_html(&container().role(ContainerRole::Nav));
+        assert!(html.starts_with("<nav ") && html.ends_with("</nav>"));
+    }
+}
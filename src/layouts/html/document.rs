@@ -0,0 +1,276 @@
+//! This module contains the document-level settings of `HtmlBuilder`
+//! (doctype, charset, language, title, meta and link tags) and the rendering
+//! of the document `<head>`.
+
+use std::fmt::Display;
+
+use super::{escape_attribute, HtmlBuilder};
+
+/// Default viewport of the document, scaled to the device width.
+pub static DEFAULT_VIEWPORT: &str = "width=device-width, initial-scale=1";
+
+/// Base direction of the document text, HTML `dir`
+///
+/// ltr | rtl | auto
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    #[default]
+    Ltr,
+    Rtl,
+    Auto,
+}
+
+impl Display for Dir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dir::Ltr => write!(f, "ltr"),
+            Dir::Rtl => write!(f, "rtl"),
+            Dir::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+/// Checks that the doctype starts with `html`, e.g.
+/// `html PUBLIC "-//W3C//DTD HTML 4.01//EN"`, and can't close the declaration early.
+fn is_valid_doctype(doctype: &str) -> bool {
+    let name = doctype.split_ascii_whitespace().next().unwrap_or_default();
+    name.eq_ignore_ascii_case("html")
+        && !doctype.contains(['<', '>'])
+        && !doctype.contains(|x: char| x.is_control() && !x.is_ascii_whitespace())
+        && doctype.matches('"').count().is_multiple_of(2)
+        && doctype.matches('\'').count().is_multiple_of(2)
+}
+
+// Document-related methods
+impl HtmlBuilder {
+    /// Sets the doctype of the document, `html` by default.
+    ///
+    /// The doctype must start with `html` and can't contain `<`, `>` or
+    /// unbalanced quotes, an invalid doctype is ignored.
+    pub fn doctype(mut self, doctype: impl ToString) -> Self {
+        let doctype = doctype.to_string();
+        if is_valid_doctype(&doctype) {
+            self.doctype = Some(doctype);
+        }
+        self
+    }
+
+    /// Gets the doctype of the document.
+    pub fn get_doctype(&self) -> &str {
+        self.doctype.as_deref().unwrap_or("html")
+    }
+
+    /// Sets the character encoding of the document, `utf-8` by default.
+    pub fn charset(mut self, charset: impl ToString) -> Self {
+        self.charset = Some(charset.to_string());
+        self
+    }
+
+    /// Gets the character encoding of the document.
+    pub fn get_charset(&self) -> &str {
+        self.charset.as_deref().unwrap_or("utf-8")
+    }
+
+    /// Sets the language of the document, e.g. `en` or `uk-UA`.
+    pub fn lang(mut self, lang: impl ToString) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    /// Gets the language of the document.
+    pub fn get_lang(&self) -> Option<&str> {
+        self.lang.as_deref()
+    }
+
+    /// Sets the base direction of the document text.
+    pub fn dir(mut self, dir: Dir) -> Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    /// Gets the base direction of the document text.
+    pub fn get_dir(&self) -> Option<Dir> {
+        self.dir
+    }
+
+    /// Sets the title of the document.
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Gets the title of the document.
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Sets the description of the document (`<meta name="description">`).
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Gets the description of the document.
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Sets the viewport of the document, [DEFAULT_VIEWPORT] by default.
+    pub fn viewport(mut self, viewport: impl ToString) -> Self {
+        self.viewport = Some(viewport.to_string());
+        self
+    }
+
+    /// Gets the viewport of the document.
+    pub fn get_viewport(&self) -> &str {
+        self.viewport.as_deref().unwrap_or(DEFAULT_VIEWPORT)
+    }
+
+    /// Sets the URL of the favicon (`<link rel="icon">`).
+    pub fn favicon(mut self, favicon: impl ToString) -> Self {
+        self.favicon = Some(favicon.to_string());
+        self
+    }
+
+    /// Gets the URL of the favicon.
+    pub fn get_favicon(&self) -> Option<&str> {
+        self.favicon.as_deref()
+    }
+
+    /// Adds a `<meta name content>` tag.
+    pub fn meta(mut self, name: impl ToString, content: impl ToString) -> Self {
+        self.metas.push((name.to_string(), content.to_string()));
+        self
+    }
+
+    /// Gets the added meta tags as (name, content).
+    pub fn get_metas(&self) -> &[(String, String)] {
+        &self.metas
+    }
+
+    /// Adds a `<link rel href>` tag, e.g. `link("canonical", "https://example.com")`.
+    pub fn link(mut self, rel: impl ToString, href: impl ToString) -> Self {
+        self.links.push((rel.to_string(), href.to_string()));
+        self
+    }
+
+    /// Gets the added link tags as (rel, href).
+    pub fn get_links(&self) -> &[(String, String)] {
+        &self.links
+    }
+
+    /// Builds the `<!DOCTYPE>` declaration.
+    pub fn build_doctype(&self) -> String {
+        format!("<!DOCTYPE {}>", self.get_doctype())
+    }
+
+    /// Builds the attributes of the `<html>` element (`lang`, `dir`).
+    pub fn build_html_attributes(&self) -> String {
+        let mut out = String::new();
+        if let Some(lang) = self.get_lang() {
+            out.push_str(&format!(r#" lang="{}""#, escape_attribute(lang)));
+        }
+        if let Some(dir) = self.get_dir() {
+            out.push_str(&format!(r#" dir="{dir}""#));
+        }
+        out
+    }
+
+    /// Builds the content of the `<head>` element without the style.
+    pub fn build_head(&self) -> String {
+        let mut out = format!(
+            r#"<meta charset="{}"><meta name="viewport" content="{}">"#,
+            escape_attribute(self.get_charset()),
+            escape_attribute(self.get_viewport())
+        );
+        if let Some(title) = self.get_title() {
+            out.push_str(&format!("<title>{}</title>", escape_attribute(title)));
+        }
+        let description = self.get_description().map(|x| ("description", x));
+        let metas = self
+            .get_metas()
+            .iter()
+            .map(|(x, y)| (x.as_str(), y.as_str()));
+        for (name, content) in description.into_iter().chain(metas) {
+            out.push_str(&format!(
+                r#"<meta name="{}" content="{}">"#,
+                escape_attribute(name),
+                escape_attribute(content)
+            ));
+        }
+        let favicon = self.get_favicon().map(|x| ("icon", x));
+        let links = self
+            .get_links()
+            .iter()
+            .map(|(x, y)| (x.as_str(), y.as_str()));
+        for (rel, href) in favicon.into_iter().chain(links) {
+            out.push_str(&format!(
+                r#"<link rel="{}" href="{}">"#,
+                escape_attribute(rel),
+                escape_attribute(href)
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod test_document {
+    use crate::{components::text, layouts::html::html_builder};
+
+    use super::Dir;
+
+    #[test]
+    fn defaults() {
+        let html = html_builder()
+            .component(text().content("Привіт"))
+            .build_as_html();
+        assert!(html.starts_with(
+            r#"<!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1"><style>"#
+        ));
+        assert!(!html.contains("<title>"));
+    }
+
+    #[test]
+    fn settings() {
+        let html = html_builder()
+            .doctype("html")
+            .lang("uk")
+            .dir(Dir::Rtl)
+            .title("zen <rs>")
+            .description("Cards & pages")
+            .viewport("width=600")
+            .favicon("/favicon.svg")
+            .meta("theme-color", "#000")
+            .link("canonical", "https://example.com")
+            .build_as_html();
+        assert!(html.starts_with(r#"<!DOCTYPE html><html lang="uk" dir="rtl"><head>"#));
+        assert!(html.contains(r#"<meta name="viewport" content="width=600">"#));
+        assert!(html.contains("<title>zen &lt;rs&gt;</title>"));
+        assert!(html.contains(r#"<meta name="description" content="Cards &amp; pages">"#));
+        assert!(html.contains(r##"<meta name="theme-color" content="#000">"##));
+        assert!(html.contains(r#"<link rel="icon" href="/favicon.svg">"#));
+        assert!(html.contains(r#"<link rel="canonical" href="https://example.com">"#));
+    }
+
+    #[test]
+    fn doctype() {
+        let html = html_builder()
+            .doctype(r#"html PUBLIC "-//W3C//DTD HTML 4.01//EN""#)
+            .build_as_html();
+        assert!(html.starts_with(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01//EN"><html>"#));
+        for invalid in [
+            "html><script>alert(1)</script",
+            r#"html PUBLIC "-//W3C"#,
+            "svg",
+            "",
+        ] {
+            let builder = html_builder().doctype(invalid);
+            assert_eq!(builder.get_doctype(), "html");
+            assert!(builder.build_as_html().starts_with("<!DOCTYPE html><html>"));
+        }
+        // an invalid doctype keeps the previous one
+        let builder = html_builder().doctype("HTML").doctype("html>");
+        assert_eq!(builder.build_doctype(), "<!DOCTYPE HTML>");
+    }
+}
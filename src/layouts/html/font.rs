@@ -0,0 +1,127 @@
+//! This module contains the `font_face_css` function for rendering a `FontFace`
+//! into a CSS `@font-face` rule.
+//!
+//! Font faces with [FontSource::Data] are embedded as base64 `data:` urls, so
+//! the generated page doesn't depend on any external file.
+
+use crate::aspects::{escape_css_string, FontFace, FontName, FontSource};
+
+/// Renders a `FontFace` into a CSS `@font-face` rule.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::{aspects::font_url, layouts::html::font_face_css};
+///
+/// let face = font_url("Inter", "https://example.com/inter.woff2");
+/// let css = font_face_css(&face);
+/// assert_eq!(
+///     css,
+///     "@font-face { font-family: 'Inter'; src: url('https://example.com/inter.woff2') format('woff2'); font-display: swap; }"
+/// );
+/// ```
+pub fn font_face_css(face: &FontFace) -> String {
+    let family = FontName::Named(face.get_family());
+    let format = face
+        .get_format()
+        .map(|x| format!(" format('{x}')"))
+        .unwrap_or_default();
+    let src = match face.get_source() {
+        FontSource::Url(url) => format!("url('{}'){format}", escape_css_string(url)),
+        FontSource::Data(data) => {
+            let mime = face
+                .get_format()
+                .map(|x| x.mime())
+                .unwrap_or("application/octet-stream");
+            let data = base64(data);
+            format!("url('data:{mime};base64,{data}'){format}")
+        }
+    };
+    let weight = match face.get_weight() {
+        Some((min, max)) if min == max => format!(" font-weight: {min};"),
+        Some((min, max)) => format!(" font-weight: {min} {max};"),
+        None => "".to_string(),
+    };
+    let style = if face.get_italic() {
+        " font-style: italic;"
+    } else {
+        ""
+    };
+    format!(
+        "@font-face {{ font-family: {family}; src: {src};{weight}{style} font-display: swap; }}"
+    )
+}
+
+/// Encodes bytes with the standard base64 alphabet and padding.
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_font {
+    use crate::aspects::{font_file, font_url, FontFace, FontFormat, Weight};
+
+    use super::{base64, font_face_css};
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xff, 0xfe, 0xfd]), "//79");
+    }
+
+    #[test]
+    fn inline_data() {
+        let face = FontFace::data("Doto", b"foo".to_vec(), Some(FontFormat::Ttf))
+            .weight_range(Weight::Thin, Weight::Heavy)
+            .is_italic();
+        let css = font_face_css(&face);
+        assert!(css.contains("src: url('data:font/ttf;base64,Zm9v') format('truetype');"));
+        assert!(css.contains("font-weight: 100 900;"));
+        assert!(css.contains("font-style: italic;"));
+    }
+
+    #[test]
+    fn inline_file() {
+        let path = std::env::temp_dir().join("zen_rs_test_font.woff2");
+        std::fs::write(&path, b"fo").unwrap();
+        let face = font_file("Local", &path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(face.get_format(), Some(FontFormat::Woff2));
+        let css = font_face_css(&face);
+        assert!(css.contains("font-family: 'Local';"));
+        assert!(css.contains("url('data:font/woff2;base64,Zm8=') format('woff2')"));
+        assert!(font_file("Missing", "/nonexistent/font.ttf").is_err());
+    }
+
+    #[test]
+    fn url_is_escaped() {
+        let face = font_url(
+            "Inter",
+            "https://example.com/it's.woff2'); } body { color: red",
+        );
+        let css = font_face_css(&face);
+        assert!(css
+            .contains(r"src: url('https://example.com/it\27 s.woff2\27 ); } body { color: red');"));
+        assert!(font_face_css(&font_url("A", "a\\b\nc")).contains(r"url('a\5c b\a c')"));
+    }
+}
@@ -0,0 +1,563 @@
+//! This module contains the formatting of the generated HTML and CSS,
+//! see [HtmlFormat].
+//!
+//! Whitespace between elements is insignificant only when an element
+//! contains nothing but other elements and every gap between them touches a
+//! block-level element, so text and inline elements (e.g. `<b>a</b> <i>b</i>`)
+//! are never split, joined or reindented.
+
+/// Formatting of the output of `HtmlBuilder`
+///
+/// plain | minified | pretty
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HtmlFormat {
+    /// As rendered by the components, on one line
+    #[default]
+    Plain,
+    /// Without insignificant whitespace and with short CSS
+    Minified,
+    /// Indented, one element and one CSS declaration per line
+    Pretty,
+}
+
+/// Indentation of one level of [HtmlFormat::Pretty].
+static INDENT: &str = "  ";
+
+/// Elements without content and closing tag.
+static VOID_ELEMENTS: [&str; 14] = [
+    "!doctype", "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "source", "track", "wbr",
+];
+
+/// Block-level elements, whitespace around them is insignificant.
+static BLOCK_ELEMENTS: [&str; 40] = [
+    "!doctype",
+    "address",
+    "article",
+    "aside",
+    "base",
+    "blockquote",
+    "body",
+    "dd",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "li",
+    "link",
+    "main",
+    "meta",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "style",
+    "table",
+    "title",
+    "ul",
+];
+
+/// SVG elements with only insignificant whitespace between their children.
+static SVG_CONTAINERS: [&str; 4] = ["defs", "g", "svg", "symbol"];
+
+/// Elements with raw text content.
+static RAW_ELEMENTS: [&str; 3] = ["script", "style", "title"];
+
+/// Formats HTML (e.g. of `HtmlBuilder::build`) deterministically.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::layouts::html::{format_html, HtmlFormat};
+///
+/// let html = r#"<div style="display: flex;  gap: 4px;"> <p>Hi <b>there</b></p> </div>"#;
+/// assert_eq!(
+///     format_html(html, HtmlFormat::Minified),
+///     r#"<div style="display:flex;gap:4px"><p>Hi <b>there</b></p></div>"#
+/// );
+/// assert_eq!(
+///     format_html(html, HtmlFormat::Pretty),
+///     "<div style=\"display: flex; gap: 4px;\">\n  <p>Hi <b>there</b></p>\n</div>\n"
+/// );
+/// ```
+pub fn format_html(html: &str, format: HtmlFormat) -> String {
+    let mut out = String::new();
+    match format {
+        HtmlFormat::Plain => out.push_str(html),
+        HtmlFormat::Minified => inline("", &parse(html), &mut out, minify_style),
+        HtmlFormat::Pretty => pretty(&parse(html), 0, &mut out),
+    }
+    out
+}
+
+/// Removes insignificant whitespace and the last `;` of blocks from CSS.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::layouts::html::minify_css;
+///
+/// assert_eq!(
+///     minify_css(":root { --a: 1px; }  h1, h2 { font-family: 'Inter Tight', serif; }"),
+///     ":root{--a:1px}h1,h2{font-family:'Inter Tight',serif}"
+/// );
+/// ```
+pub fn minify_css(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut quote = None;
+    let mut space = false;
+    for x in css.chars() {
+        if let Some(q) = quote {
+            out.push(x);
+            if x == q {
+                quote = None;
+            }
+            continue;
+        }
+        if x.is_whitespace() {
+            space = true;
+            continue;
+        }
+        let separator = |x: char| matches!(x, '{' | '}' | ';' | ',');
+        if space && !out.is_empty() && !separator(x) && !out.ends_with(|x| separator(x) || x == ':')
+        {
+            out.push(' ');
+        }
+        space = false;
+        match x {
+            '"' | '\'' => quote = Some(x),
+            '}' if out.ends_with(';') => {
+                out.pop();
+            }
+            ';' if out.is_empty() || out.ends_with(['{', ';']) => continue,
+            _ => {}
+        }
+        out.push(x);
+    }
+    out
+}
+
+/// Formats CSS with one rule and one declaration per line.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::layouts::html::pretty_css;
+///
+/// assert_eq!(
+///     pretty_css("@media (prefers-color-scheme: dark) { :root { --a: 1px } }", 0),
+///     "@media (prefers-color-scheme: dark) {\n  :root {\n    --a: 1px;\n  }\n}\n"
+/// );
+/// ```
+pub fn pretty_css(css: &str, depth: usize) -> String {
+    let css = minify_css(css);
+    let mut out = String::new();
+    let mut level = 0;
+    let mut line = |level: usize, line: &str| {
+        out.push_str(&INDENT.repeat(depth + level));
+        out.push_str(line);
+        out.push('\n');
+    };
+    for (statement, end) in css_statements(&css) {
+        match end {
+            '{' => {
+                line(level, &format!("{} {{", spaced(statement)));
+                level += 1;
+            }
+            '}' => {
+                if !statement.is_empty() {
+                    line(level, &format!("{};", declaration(statement)));
+                }
+                level = level.saturating_sub(1);
+                line(level, "}");
+            }
+            _ if level == 0 => line(level, &format!("{statement};")),
+            _ => line(level, &format!("{};", declaration(statement))),
+        }
+    }
+    out
+}
+
+/// Node of a parsed HTML document.
+#[derive(Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+/// Element of a parsed HTML document.
+#[derive(Debug)]
+struct Element {
+    name: String,
+    open: String,
+    close: Option<String>,
+    children: Vec<Node>,
+}
+
+/// Parses HTML into nodes, unmatched closing tags are dropped.
+fn parse(html: &str) -> Vec<Node> {
+    let element = |name: &str, open: &str| Element {
+        name: name.to_string(),
+        open: open.to_string(),
+        close: None,
+        children: vec![],
+    };
+    let mut stack = vec![element("", "")];
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').map_or(rest.len(), |x| x + 3);
+            let name = tag_name(tag);
+            if let Some(index) = stack
+                .iter()
+                .rposition(|x| x.name == name)
+                .filter(|x| *x > 0)
+            {
+                while stack.len() > index + 1 {
+                    close(&mut stack, None);
+                }
+                close(&mut stack, Some(&rest[..end]));
+            }
+            rest = &rest[end..];
+        } else if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(rest.len(), |x| x + 3);
+            push(&mut stack, Node::Text(rest[..end].to_string()));
+            rest = &rest[end..];
+        } else if rest.starts_with('<')
+            && rest[1..].starts_with(|x: char| x.is_ascii_alphabetic() || x == '!')
+        {
+            let end = tag_end(rest);
+            let (open, name) = (&rest[..end], tag_name(&rest[1..]));
+            rest = &rest[end..];
+            if open.ends_with("/>") || VOID_ELEMENTS.contains(&name.as_str()) {
+                push(&mut stack, Node::Element(element(&name, open)));
+                continue;
+            }
+            let mut element = element(&name, open);
+            if RAW_ELEMENTS.contains(&name.as_str()) {
+                let end = rest.find(&format!("</{name}")).unwrap_or(rest.len());
+                element.children.push(Node::Text(rest[..end].to_string()));
+                rest = &rest[end..];
+            }
+            stack.push(element);
+        } else {
+            let end = rest[1..].find('<').map_or(rest.len(), |x| x + 1);
+            push(&mut stack, Node::Text(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+    while stack.len() > 1 {
+        close(&mut stack, None);
+    }
+    stack.pop().map(|x| x.children).unwrap_or_default()
+}
+
+/// Adds a node to the innermost open element.
+fn push(stack: &mut [Element], node: Node) {
+    if let Some(parent) = stack.last_mut() {
+        parent.children.push(node);
+    }
+}
+
+/// Closes the innermost open element.
+fn close(stack: &mut Vec<Element>, tag: Option<&str>) {
+    if let Some(mut element) = stack.pop() {
+        element.close = tag.map(str::to_string);
+        push(stack, Node::Element(element));
+    }
+}
+
+/// Lowercased name of a tag, after `<` or `</`.
+fn tag_name(tag: &str) -> String {
+    tag.chars()
+        .take_while(|x| x.is_ascii_alphanumeric() || matches!(x, '!' | '-' | ':'))
+        .map(|x| x.to_ascii_lowercase())
+        .collect()
+}
+
+/// Length of the tag at the start of `html`, including `>`.
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (i, x) in html.char_indices() {
+        match (quote, x) {
+            (Some(q), x) if x == q => quote = None,
+            (None, '"' | '\'') => quote = Some(x),
+            (None, '>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Whether the whitespace between the children of the element `name`
+/// (empty for the document) is insignificant.
+fn is_block(name: &str, children: &[Node]) -> bool {
+    let mut elements = vec![];
+    for child in children {
+        match child {
+            Node::Text(text) if !text.trim().is_empty() => return false,
+            Node::Text(_) => {}
+            Node::Element(element) => elements.push(element.name.as_str()),
+        }
+    }
+    let block = |x: &str| x.is_empty() || BLOCK_ELEMENTS.contains(&x);
+    match (elements.first(), elements.last()) {
+        _ if SVG_CONTAINERS.contains(&name) => !elements.is_empty(),
+        (Some(first), Some(last)) => {
+            (block(name) || block(first) && block(last))
+                && elements.windows(2).all(|x| block(x[0]) || block(x[1]))
+        }
+        _ => false,
+    }
+}
+
+/// Renders nodes of the element `name` without insignificant whitespace,
+/// `style` attributes are formatted by `css`.
+fn inline(name: &str, nodes: &[Node], out: &mut String, css: fn(&str) -> String) {
+    let block = is_block(name, nodes);
+    for node in nodes {
+        match node {
+            Node::Text(_) if block => {}
+            Node::Text(text) => out.push_str(text),
+            Node::Element(element) => {
+                out.push_str(&minify_tag(&element.open, css));
+                if element.name == "style" {
+                    out.push_str(&minify_css(&raw_text(element)));
+                } else {
+                    inline(&element.name, &element.children, out, css);
+                }
+                out.push_str(element.close.as_deref().unwrap_or_default());
+            }
+        }
+    }
+}
+
+/// Renders nodes indented, one element per line.
+fn pretty(nodes: &[Node], depth: usize, out: &mut String) {
+    let indent = INDENT.repeat(depth);
+    for node in nodes {
+        match node {
+            Node::Text(text) if text.trim().is_empty() => {}
+            Node::Text(text) => out.push_str(&format!("{indent}{}\n", text.trim())),
+            Node::Element(element) => {
+                out.push_str(&indent);
+                out.push_str(&minify_tag(&element.open, pretty_style));
+                let close = element.close.as_deref().unwrap_or_default();
+                if element.name == "style" {
+                    let css = pretty_css(&raw_text(element), depth + 1);
+                    out.push_str(&format!("\n{css}{indent}{close}\n"));
+                } else if is_block(&element.name, &element.children) {
+                    out.push('\n');
+                    pretty(&element.children, depth + 1, out);
+                    out.push_str(&format!("{indent}{close}\n"));
+                } else {
+                    inline(&element.name, &element.children, out, pretty_style);
+                    out.push_str(&format!("{close}\n"));
+                }
+            }
+        }
+    }
+}
+
+/// Raw text content of an element.
+fn raw_text(element: &Element) -> String {
+    element
+        .children
+        .iter()
+        .map(|x| match x {
+            Node::Text(text) => text.as_str(),
+            Node::Element(_) => "",
+        })
+        .collect()
+}
+
+/// Collapses whitespace of a tag, `style` attributes are formatted by `css`.
+fn minify_tag(tag: &str, css: fn(&str) -> String) -> String {
+    let mut out = String::with_capacity(tag.len());
+    let mut quote = None;
+    let mut value = String::new();
+    let mut space = false;
+    for x in tag.chars() {
+        if let Some(q) = quote {
+            if x != q {
+                value.push(x);
+                continue;
+            }
+            let name = out.trim_end_matches(['=', q]);
+            let name = &name[name.rfind([' ', '<']).map_or(0, |x| x + 1)..];
+            if name.eq_ignore_ascii_case("style") {
+                out.push_str(&css(&value));
+            } else {
+                out.push_str(&value);
+            }
+            value.clear();
+            quote = None;
+        } else if x.is_whitespace() {
+            space = true;
+            continue;
+        } else if matches!(x, '"' | '\'') {
+            quote = Some(x);
+        }
+        if space && !matches!(x, '>' | '/' | '=') && !out.ends_with('=') {
+            out.push(' ');
+        }
+        space = false;
+        out.push(x);
+    }
+    out.push_str(&value);
+    out
+}
+
+/// Minified CSS of a `style` attribute.
+fn minify_style(css: &str) -> String {
+    minify_css(css).trim_end_matches(';').to_string()
+}
+
+/// CSS of a `style` attribute with one space between declarations.
+fn pretty_style(css: &str) -> String {
+    css_statements(&minify_css(&format!("{css};")))
+        .into_iter()
+        .map(|(x, _)| format!("{};", declaration(x)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Declaration of minified CSS with a space after the property.
+fn declaration(declaration: &str) -> String {
+    match declaration.split_once(':') {
+        Some((property, value)) => format!("{property}: {}", spaced(value)),
+        None => spaced(declaration),
+    }
+}
+
+/// Minified CSS with a space after commas, and after colons of media queries.
+fn spaced(css: &str) -> String {
+    let media = css.starts_with("@media") || css.starts_with("@supports");
+    let mut out = String::with_capacity(css.len());
+    let (mut quote, mut parens) = (None, 0);
+    for x in css.chars() {
+        out.push(x);
+        match (quote, x) {
+            (Some(q), x) if x == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(x),
+            (None, '(') => parens += 1,
+            (None, ')') => parens -= 1,
+            (None, ',') => out.push(' '),
+            (None, ':') if media && parens > 0 => out.push(' '),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Splits minified CSS into statements with their end (`{`, `;` or `}`).
+fn css_statements(css: &str) -> Vec<(&str, char)> {
+    let mut out = vec![];
+    let (mut quote, mut parens, mut start) = (None, 0, 0);
+    for (i, x) in css.char_indices() {
+        match (quote, x) {
+            (Some(q), x) if x == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(x),
+            (None, '(') => parens += 1,
+            (None, ')') => parens -= 1,
+            (None, '{' | ';' | '}') if parens == 0 => {
+                out.push((&css[start..i], x));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < css.len() {
+        out.push((&css[start..], ';'));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test_format {
+    use crate::{
+        components::{github::github_outlined, h::text_base, text},
+        dynamic_stack::vstack,
+        layouts::html::html_builder,
+    };
+
+    use super::{format_html, minify_css, HtmlFormat};
+
+    fn page() -> crate::layouts::html::HtmlBuilder {
+        html_builder().title("zen").component(vstack((
+            github_outlined().title("GitHub"),
+            text().content("Hi  there"),
+            text_base("zen"),
+        )))
+    }
+
+    #[test]
+    fn plain() {
+        let html = page().build_as_html();
+        assert!(!html.contains('\n'));
+        assert_eq!(format_html(&html, HtmlFormat::Plain), html);
+        assert!(!page().build().contains('\n'));
+    }
+
+    #[test]
+    fn minified() {
+        let html = page().format(HtmlFormat::Minified).build_as_html();
+        assert!(!html.contains('\n') && !html.contains(" >") && !html.contains("; "));
+        assert!(html.contains("<style>.zen-theme-"));
+        assert!(html.contains("{--zen-color-background:rgba(255,255,255,100);"));
+        assert!(html.contains(">Hi  there</div>"));
+        assert!(html.contains(r#"style="display:block;justify-content:flex-start;"#));
+        // idempotent
+        assert_eq!(format_html(&html, HtmlFormat::Minified), html);
+        assert_eq!(minify_css("a { b: 'x  ;y' ; }"), "a{b:'x  ;y'}");
+    }
+
+    #[test]
+    fn inline_whitespace() {
+        let html = "<p><b>a</b> <i>b</i></p>";
+        assert_eq!(format_html(html, HtmlFormat::Minified), html);
+        assert_eq!(format_html(html, HtmlFormat::Pretty), format!("{html}\n"));
+        let html = "<div> <svg> <path/> </svg> <span>a</span> </div>";
+        assert_eq!(
+            format_html(html, HtmlFormat::Minified),
+            "<div> <svg><path/></svg> <span>a</span> </div>"
+        );
+        let html = "<div> <div>a</div> <svg> <path/> </svg> </div>";
+        assert_eq!(
+            format_html(html, HtmlFormat::Minified),
+            "<div><div>a</div><svg><path/></svg></div>"
+        );
+    }
+
+    #[test]
+    fn pretty() {
+        let html = page().format(HtmlFormat::Pretty).build_as_html();
+        assert!(
+            html.starts_with("<!DOCTYPE html>\n<html>\n  <head>\n    <meta charset=\"utf-8\">\n")
+        );
+        assert!(html.contains("\n    <title>zen</title>\n"));
+        assert!(html.contains("\n    <style>\n      .zen-theme-"));
+        assert!(html.contains(" {\n        --zen-color-background: rgba(255, 255, 255, 100);\n"));
+        assert!(html.contains("\n      h1, h2, h3, h4, h5, h6 {\n"));
+        assert!(html.contains("\n        <title>GitHub</title>\n        <path"));
+        assert!(html.contains(">Hi  there</div>\n"));
+        assert!(html.ends_with("  </body>\n</html>\n"));
+        // idempotent
+        assert_eq!(format_html(&html, HtmlFormat::Pretty), html);
+    }
+}
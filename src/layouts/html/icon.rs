@@ -3,23 +3,102 @@
 //!
 //! The `icon_html` function takes an `Icon` component, extracts its properties
 //! (such as foreground and background colors, width, height, viewBox, stroke
-//! attributes, and paths), and generates an SVG element with the appropriate
-//! attributes and content. The paths are wrapped inside `<path>` tags, and
-//! the SVG attributes are applied accordingly, such as stroke and fill styles.
+//! attributes, and elements), and generates an SVG element with the appropriate
+//! attributes and content. The elements are rendered as `<path>`, `<circle>`,
+//! `<rect>`, `<line>` and `<g>` tags with their own paint, unset paint is
+//! inherited from the stroke and fill of the `<svg>`.
 //!
 //! # Example
 //! ```rust
-//! let icon = Icon::new()
-//!     .foreground_color((255, 0, 0))
-//!     .background_color((0, 0, 0))
-//!     .width(24)
-//!     .height(24)
-//!     .content(vec!["M10 10 H 90 V 90 H 10 Z"]);
+//! use zen_rs::{components::icon, layouts::html::icon_html};
+//!
+//! let icon = icon()
+//!     .foreground_color("rgb(255, 0, 0)")
+//!     .background_color("rgb(0, 0, 0)")
+//!     .size(24)
+//!     .view_box((0, 0, 100, 100))
+//!     .content("M10 10 H 90 V 90 H 10 Z");
 //! let svg = icon_html(&icon);
-//! assert_eq!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 100 100" fill="rgb(0, 0, 0)" stroke="rgb(255, 0, 0)" stroke-linecap="butt" stroke-linejoin="miter" stroke-width="1"><path d="M10 10 H 90 V 90 H 10 Z" fill="rgb(0, 0, 0)" /></svg>"#);
+//! assert!(svg.contains(r#"viewBox="0 0 100 100""#));
+//! assert!(svg.contains(r#"fill="rgb(0, 0, 0)""#));
+//! assert!(svg.contains(r#"<path d="M10 10 H 90 V 90 H 10 Z"/>"#));
 //! ```
 
-use crate::components::{icon::Icon, XMLNS};
+use std::collections::BTreeMap;
+
+use super::{align_css, attributes_html, dark_value, escape_attribute, fnv1a};
+use crate::{
+    aspects::{AspectRatioAlign, SvgElement, SvgShape},
+    components::{icon::Icon, Components, XMLNS},
+};
+
+/// Renders a `SvgElement` of an icon into an SVG tag with its paint attributes.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::{aspects::SvgElement, layouts::html::svg_element_html};
+///
+/// let circle = SvgElement::circle(12.0, 12.0, 4.5).fill("#f00").opacity(0.5);
+/// assert_eq!(
+///     svg_element_html(&circle),
+///     r##"<circle cx="12" cy="12" r="4.5" fill="#f00" opacity="0.5"/>"##
+/// );
+/// ```
+pub fn svg_element_html(element: &SvgElement) -> String {
+    let mut paint = String::new();
+    if let Some(fill) = element.get_fill() {
+        paint.push_str(&format!(
+            r#" fill="{}""#,
+            escape_attribute(&fill.to_string())
+        ));
+    }
+    if let Some(fill_rule) = element.get_fill_rule() {
+        paint.push_str(&format!(r#" fill-rule="{fill_rule}""#));
+    }
+    if let Some(stroke) = element.get_stroke() {
+        paint.push_str(&format!(
+            r#" stroke="{}""#,
+            escape_attribute(&stroke.to_string())
+        ));
+    }
+    if let Some(stroke_width) = element.get_stroke_width() {
+        paint.push_str(&format!(r#" stroke-width="{stroke_width}""#));
+    }
+    if let Some(opacity) = element.get_opacity() {
+        paint.push_str(&format!(r#" opacity="{opacity}""#));
+    }
+    if let Some(transform) = element.get_transform() {
+        paint.push_str(&format!(r#" transform="{}""#, escape_attribute(transform)));
+    }
+    match element.get_shape() {
+        SvgShape::Path(d) => format!(r#"<path d="{}"{paint}/>"#, escape_attribute(d)),
+        SvgShape::Circle { cx, cy, r } => {
+            format!(r#"<circle cx="{cx}" cy="{cy}" r="{r}"{paint}/>"#)
+        }
+        SvgShape::Rect {
+            x,
+            y,
+            width,
+            height,
+            rx,
+            ry,
+        } => {
+            let radius = if *rx != 0.0 || *ry != 0.0 {
+                format!(r#" rx="{rx}" ry="{ry}""#)
+            } else {
+                "".to_string()
+            };
+            format!(r#"<rect x="{x}" y="{y}" width="{width}" height="{height}"{radius}{paint}/>"#)
+        }
+        SvgShape::Line { x1, y1, x2, y2 } => {
+            format!(r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}"{paint}/>"#)
+        }
+        SvgShape::Group(children) => {
+            let children: String = children.iter().map(svg_element_html).collect();
+            format!("<g{paint}>{children}</g>")
+        }
+    }
+}
 
 /// Renders an `Icon` component into an SVG string with the specified attributes
 /// such as foreground and background colors, width, height, viewBox, and paths.
@@ -34,67 +113,371 @@ use crate::components::{icon::Icon, XMLNS};
 ///
 /// # Example
 /// ```rust
-/// let icon = Icon::new()
-///     .foreground_color((255, 0, 0))
-///     .background_color((0, 0, 0))
+/// use zen_rs::{components::icon, layouts::html::icon_html};
+///
+/// let icon = icon()
+///     .foreground_color("rgb(255, 0, 0)")
+///     .background_color("rgb(0, 0, 0)")
 ///     .width(24)
 ///     .height(24)
-///     .content(vec!["M10 10 H 90 V 90 H 10 Z"]);
+///     .content("M10 10 H 90 V 90 H 10 Z");
 /// let svg = icon_html(&icon);
-/// assert_eq!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 100 100" fill="rgb(0, 0, 0)" stroke="rgb(255, 0, 0)" stroke-linecap="butt" stroke-linejoin="miter" stroke-width="1"><path d="M10 10 H 90 V 90 H 10 Z" fill="rgb(0, 0, 0)" /></svg>"#);
+/// assert!(svg.contains(r#"width="24""#));
+/// assert!(svg.contains(r#"stroke="rgb(255, 0, 0)""#));
 /// ```
 pub fn icon_html(component: &Icon) -> String {
+    let content: String = component
+        .get_content()
+        .iter()
+        .map(svg_element_html)
+        .collect();
+    svg_html(component, &view_box_attributes(component), &content)
+}
+
+/// Returns the sprite sheet with every distinct icon of a component tree.
+pub fn icon_sprite(component: &Components) -> IconSprite {
+    IconSprite::new(component)
+}
+
+/// Sprite sheet of the distinct icons of a component tree.
+///
+/// Identical content and viewBox share one `<symbol>`. Symbols are ordered
+/// by the first appearance of their icon and get ids from a hash of their
+/// content (`zen-icon-<hash>`), so ids are stable across builds and toolchains,
+/// and several outputs inlined into one page don't reference each other's symbols.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IconSprite {
+    /// Index of each symbol by its content.
+    ids: BTreeMap<String, usize>,
+    /// Id and content of each symbol in order of appearance.
+    symbols: Vec<(String, String)>,
+}
+
+impl IconSprite {
+    /// Collects every distinct icon of a component tree, including the
+    /// rendered trees of custom components.
+    pub fn new(component: &Components) -> Self {
+        let mut out = Self::default();
+        component.walk(|component| {
+            if let Components::Icon(icon) = component {
+                out.push(icon);
+            }
+        });
+        out
+    }
+
+    /// Adds an icon to the sprite sheet and returns the id of its symbol.
+    pub fn push(&mut self, icon: &Icon) -> String {
+        let content = symbol_content(icon);
+        if let Some(index) = self.ids.get(&content) {
+            return self.symbols[*index].0.clone();
+        }
+        let hash = format!("zen-icon-{:016x}", fnv1a(&content));
+        // distinct contents with the same hash get a suffix
+        let id = (0..)
+            .map(|x| match x {
+                0 => hash.clone(),
+                x => format!("{hash}-{x}"),
+            })
+            .find(|id| self.symbols.iter().all(|(x, _)| x != id))
+            .unwrap_or(hash);
+        self.ids.insert(content.clone(), self.symbols.len());
+        self.symbols.push((id.clone(), content));
+        id
+    }
+
+    /// Gets the id of the `<symbol>` of an icon, `None` if it's not in the sprite sheet.
+    pub fn get_id(&self, icon: &Icon) -> Option<String> {
+        self.ids
+            .get(&symbol_content(icon))
+            .map(|x| self.symbols[*x].0.clone())
+    }
+
+    /// Gets the number of symbols.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Whether the sprite sheet has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Renders every symbol, in order of appearance.
+    pub fn symbols_html(&self) -> String {
+        self.symbols
+            .iter()
+            .map(|(id, x)| format!(r#"<symbol id="{id}"{x}</symbol>"#))
+            .collect()
+    }
+
+    /// Renders the hidden sprite sheet, empty without symbols.
+    pub fn html(&self) -> String {
+        if self.is_empty() {
+            return "".to_string();
+        }
+        format!(
+            r#"<svg xmlns="{XMLNS}" style="display: none;">{}</svg>"#,
+            self.symbols_html()
+        )
+    }
+}
+
+/// Renders an icon as reference to its `<symbol>` in the sprite sheet.
+///
+/// Colors, size and stroke attributes stay on the `<svg>` of each instance,
+/// so the same symbol can be reused with different paint. Icons which are
+/// not in the sprite sheet are rendered inline.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::{
+///     components::icon,
+///     dynamic_stack::hstack,
+///     layouts::html::{icon_sprite, icon_use_html},
+/// };
+///
+/// let star = icon().view_box((0, 0, 24, 24)).content("M12 2l3 7h7l-6 5l2 8l-6 -5l-6 5l2 -8l-6 -5h7z");
+/// let sprite = icon_sprite(&hstack((star.clone(), star.clone().size(16))).into());
+/// assert_eq!(sprite.len(), 1);
+/// let id = sprite.get_id(&star).unwrap();
+/// let svg = icon_use_html(&star.foreground_color("gold"), &sprite);
+/// assert!(svg.contains(&format!(r##"<use href="#{id}"/>"##)));
+/// assert!(!svg.contains("<path"));
+/// ```
+pub fn icon_use_html(component: &Icon, sprite: &IconSprite) -> String {
+    match sprite.get_id(component) {
+        Some(id) => svg_html(component, "", &format!(r##"<use href="#{id}"/>"##)),
+        None => icon_html(component),
+    }
+}
+
+/// Renders the hidden sprite sheet with every distinct icon of a component tree.
+///
+/// Returns an empty string for trees without icons.
+pub fn icon_sprite_html(component: &Components) -> String {
+    icon_sprite(component).html()
+}
+
+/// Attributes and content of the `<symbol>` of an icon (after the tag name).
+fn symbol_content(component: &Icon) -> String {
+    let content: String = component
+        .get_content()
+        .iter()
+        .map(svg_element_html)
+        .collect();
+    format!("{}>{content}", view_box_attributes(component))
+}
+
+/// `viewBox` and `preserveAspectRatio` attributes of an icon, prefixed with a space.
+fn view_box_attributes(component: &Icon) -> String {
+    let (bl, bt, bb, br) = component.get_view_box();
+    let vb = format!(r#" viewBox="{bl} {bt} {bb} {br}""#);
+    match component.get_preserve_aspect_ratio() {
+        None => vb,
+        Some((AspectRatioAlign::None, _)) => format!(r#"{vb} preserveAspectRatio="none""#),
+        Some((align, meet_or_slice)) => {
+            format!(r#"{vb} preserveAspectRatio="{align} {meet_or_slice}""#)
+        }
+    }
+}
+
+/// Outer `<svg>` of an icon with its size, paint and alignment.
+fn svg_html(component: &Icon, view_box: &str, content: &str) -> String {
     let xmlns = XMLNS;
     // data
     let fg = component.get_foreground_color().to_string();
     let bg = component.get_background_color().to_string();
     let w = component.get_width();
     let h = component.get_height();
-    let (bl, bt, bb, br) = component.get_view_box();
-
-    // content
-    let content = component.get_content();
-
-    // content formating
-    let first_path_wrap = |path: &str| format!(r#"<path stroke="none" d="{path}" fill="{bg}" />"#);
-    let path_wrap = |path: &str| format!(r#"<path d="{path}"/>"#);
-    let first = if let Some(path) = content.first() {
-        first_path_wrap(path)
-    } else {
-        "".to_string()
-    };
-    let content = &content[1..];
-    let paths: String = content.iter().map(|x| path_wrap(x)).collect();
 
     // Specific svg attributes
     let slp = component
         .get_stroke_linecap()
-        .map(|x| format!(r#"stroke-linecap="{x}""#))
+        .map(|x| format!(r#" stroke-linecap="{x}""#))
         .unwrap_or_default();
     let slj = component
         .get_stroke_linejoin()
-        .map(|x| format!(r#"stroke-linejoin="{x}""#))
+        .map(|x| format!(r#" stroke-linejoin="{x}""#))
         .unwrap_or_default();
     let sw = component
         .get_stroke_width()
-        .map(|x| format!(r#"stroke-width="{x}""#))
+        .map(|x| format!(r#" stroke-width="{x}""#))
         .unwrap_or_default();
-    let vb = format!(r#"viewBox="{bl} {bt} {bb} {br}""#);
+    // dark colors need CSS, presentation attributes don't support `var()`
+    let mut style = vec![];
+    if let Some(x) = component.get_align_self() {
+        style.push(format!("align-self: {};", align_css(&x)));
+    }
+    if let Some(dark) = component.get_background_color_dark() {
+        style.push(format!("fill: {};", dark_value(Some(dark), bg.clone())));
+    }
+    if let Some(dark) = component.get_foreground_color_dark() {
+        style.push(format!("stroke: {};", dark_value(Some(dark), fg.clone())));
+    }
+    let style = if style.is_empty() {
+        "".to_string()
+    } else {
+        format!(r#" style="{}""#, escape_attribute(&style.join(" ")))
+    };
+    // decorative icons are hidden, an accessible name would contradict it
+    let mut attributes = component.get_attributes().clone();
+    if component.get_decorative() {
+        attributes.retain(|x, _| x != "aria-label" && x != "aria-labelledby");
+    }
+    let attributes = attributes_html(component.get_id(), component.get_classes(), &attributes);
+
+    // accessibility
+    let (a11y, title) = match component.get_title() {
+        _ if component.get_decorative() => (
+            r#" aria-hidden="true" focusable="false""#.to_string(),
+            "".to_string(),
+        ),
+        Some(title) => {
+            let title = escape_attribute(title);
+            let label = if component.get_attributes().contains_key("aria-label") {
+                "".to_string()
+            } else {
+                format!(r#" aria-label="{title}""#)
+            };
+            (
+                format!(r#" role="img"{label}"#),
+                format!("<title>{title}</title>"),
+            )
+        }
+        None => ("".to_string(), "".to_string()),
+    };
 
     // out
+    let (fg, bg) = (escape_attribute(&fg), escape_attribute(&bg));
     format!(
-        r#"
-        <svg xmlns="{xmlns}"
-        width="{w}"
-        height="{h}"
-        {vb}
-        fill="{bg}"
-        stroke="{fg}"
-        {slp}
-        {slj}
-        {sw}>
-        {first}
-        {paths}</svg>
-    "#
+        r#"<svg xmlns="{xmlns}" width="{w}" height="{h}"{view_box} fill="{bg}" stroke="{fg}"{slp}{slj}{sw}{style}{attributes}{a11y}>{title}{content}</svg>"#
     )
 }
+
+#[cfg(test)]
+mod test_icon {
+    use crate::{
+        aspects::{AspectRatioAlign, FillRule, MeetOrSlice, SvgElement},
+        components::{github::github_outlined, icon, text, Component, Components},
+        dynamic_stack::vstack,
+    };
+
+    use super::{icon_html, icon_sprite, icon_sprite_html, icon_use_html};
+
+    #[test]
+    fn multicolor() {
+        let logo = icon()
+            .view_box((0, 0, 24, 24))
+            .element(SvgElement::rect(0.0, 0.0, 24.0, 24.0).fill("#000"))
+            .element(
+                SvgElement::group([
+                    SvgElement::circle(6.0, 6.0, 2.0).fill("#f00"),
+                    SvgElement::line(0.0, 0.0, 4.0, 4.0).stroke("#0f0"),
+                ])
+                .transform("translate(2 2)")
+                .opacity(0.8),
+            )
+            .element(
+                SvgElement::path("M0 0h4v4z")
+                    .fill_current_color()
+                    .fill_rule(FillRule::EvenOdd)
+                    .stroke_none(),
+            );
+        let html = icon_html(&logo);
+        assert!(html.contains(r##"<rect x="0" y="0" width="24" height="24" fill="#000"/>"##));
+        assert!(html.contains(r##"<g opacity="0.8" transform="translate(2 2)"><circle cx="6" cy="6" r="2" fill="#f00"/><line x1="0" y1="0" x2="4" y2="4" stroke="#0f0"/></g>"##));
+        assert!(html.contains(
+            r#"<path d="M0 0h4v4z" fill="currentColor" fill-rule="evenodd" stroke="none"/>"#
+        ));
+    }
+
+    #[test]
+    fn view_box() {
+        let html = icon_html(
+            &icon()
+                .view_box((-1, -0.5, 512, 25.5))
+                .preserve_aspect_ratio(AspectRatioAlign::XMinYMax, MeetOrSlice::Slice),
+        );
+        assert!(html.contains(r#"viewBox="-1 -0.5 512 25.5""#));
+        assert!(html.contains(r#"preserveAspectRatio="xMinYMax slice""#));
+        let html =
+            icon_html(&icon().preserve_aspect_ratio(AspectRatioAlign::None, MeetOrSlice::Slice));
+        assert!(html.contains(r#"preserveAspectRatio="none""#));
+        assert!(!icon_html(&icon()).contains("preserveAspectRatio"));
+    }
+
+    #[test]
+    fn sprite() {
+        let star = icon().view_box((0, 0, 24, 24)).content("M12 2l3 7h7z");
+        let tree = vstack((
+            github_outlined(),
+            star.clone().foreground_color("gold"),
+            star.clone().size(16),
+        ));
+        let sprite = icon_sprite(&tree.into());
+        assert_eq!(sprite.len(), 2);
+        let id = sprite.get_id(&star).unwrap();
+        assert_eq!(id, "zen-icon-22c0a9f0a58474f6");
+        assert_ne!(sprite.get_id(&github_outlined()).unwrap(), id);
+        assert!(sprite.symbols_html().ends_with(&format!(
+            r#"<symbol id="{id}" viewBox="0 0 24 24"><path d="M12 2l3 7h7z"/></symbol>"#
+        )));
+        // same symbol, same id in another output
+        assert_eq!(icon_sprite(&star.clone().into()).get_id(&star), Some(id));
+        let moved = star.clone().view_box((1, 0, 24, 24));
+        assert_eq!(sprite.get_id(&moved), None);
+        // custom components are collected through their rendered tree
+        #[derive(Debug)]
+        struct Logo;
+        impl Component for Logo {
+            fn render(&self) -> Components {
+                github_outlined().into()
+            }
+        }
+        let sprite = icon_sprite(&vstack((Logo,)).into());
+        assert!(sprite.get_id(&github_outlined()).is_some());
+        assert!(icon_use_html(&moved, &sprite).contains("<path"));
+        assert_eq!(icon_sprite_html(&text().into()), "");
+    }
+
+    #[test]
+    fn escaped() {
+        let icon = icon().foreground_color(r#"red" onload="x"#).element(
+            SvgElement::path(r#""/><script>"#)
+                .fill(r#"a"b"#)
+                .stroke("<c>")
+                .transform(r#"x" y"#),
+        );
+        let html = icon_html(&icon);
+        assert!(!html.contains("<script>") && !html.contains(r#"" onload"#));
+        assert!(html.contains(r#"stroke="red&quot; onload=&quot;x""#));
+        assert!(html.contains(
+            r#"<path d="&quot;/&gt;&lt;script&gt;" fill="a&quot;b" stroke="&lt;c&gt;" transform="x&quot; y"/>"#
+        ));
+    }
+
+    #[test]
+    fn accessibility() {
+        let html = icon_html(&github_outlined().title("GitHub & co"));
+        assert!(html.contains(r#" role="img" aria-label="GitHub &amp; co">"#));
+        assert!(html.contains("<title>GitHub &amp; co</title><path"));
+        let html = icon_html(&github_outlined().title("GitHub").aria("label", "Repo"));
+        assert!(html.contains(r#" aria-label="Repo" role="img">"#));
+        let html = icon_html(&github_outlined().title("GitHub").is_decorative());
+        assert!(html.contains(r#" aria-hidden="true" focusable="false">"#));
+        assert!(!html.contains("<title>"));
+        let html = icon_html(&github_outlined().aria("label", "Repo").is_decorative());
+        assert!(html.contains(r#" aria-hidden="true" focusable="false">"#));
+        assert!(!html.contains("aria-label"));
+        assert!(!icon_html(&github_outlined()).contains("role="));
+    }
+
+    #[test]
+    fn no_special_first_path() {
+        let html = icon_html(&github_outlined());
+        assert_eq!(html.matches("<path").count(), 1);
+        assert!(!html.contains(r#"stroke="none""#));
+        assert!(icon_html(&icon()).contains("</svg>"));
+    }
+}
@@ -0,0 +1,296 @@
+//! This module contains the Open Graph (`og:*`) and Twitter (`twitter:*`)
+//! meta tags of a page shared as a social card.
+//!
+//! **Note**: Only the meta tags are generated, the card image has to be
+//! rendered and hosted separately (e.g. a screenshot of the page) and passed by URL.
+
+use std::fmt::Display;
+
+use super::{escape_attribute, HtmlBuilder};
+
+/// Size of a card image (width, height) in pixels
+pub type CardSize = (u32, u32);
+
+/// Recommended size of an Open Graph image
+pub static OPEN_GRAPH_CARD_SIZE: CardSize = (1200, 630);
+
+/// Recommended size of a Twitter large summary image
+pub static TWITTER_CARD_SIZE: CardSize = (1200, 600);
+
+/// Type of a Twitter card, `twitter:card`
+///
+/// summary | summary_large_image
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TwitterCard {
+    Summary,
+    #[default]
+    SummaryLargeImage,
+}
+
+impl Display for TwitterCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TwitterCard::Summary => write!(f, "summary"),
+            TwitterCard::SummaryLargeImage => write!(f, "summary_large_image"),
+        }
+    }
+}
+
+/// Creates new social meta, see [SocialMeta].
+pub fn social_meta() -> SocialMeta {
+    SocialMeta::default()
+}
+
+/// Open Graph and Twitter meta of a page.
+///
+/// Title and description default to the ones of the document.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::layouts::html::{html_builder, social_meta, OPEN_GRAPH_CARD_SIZE};
+///
+/// let html = html_builder()
+///     .title("zen-rs")
+///     .social(
+///         social_meta()
+///             .url("https://github.com/TOwInOK/zen-rs")
+///             .image("https://example.com/card.png")
+///             .image_size(OPEN_GRAPH_CARD_SIZE),
+///     )
+///     .build_as_html();
+/// assert!(html.contains(r#"<meta property="og:title" content="zen-rs">"#));
+/// assert!(html.contains(r#"<meta property="og:image:width" content="1200">"#));
+/// assert!(html.contains(r#"<meta name="twitter:card" content="summary_large_image">"#));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SocialMeta {
+    title: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    image: Option<String>,
+    image_alt: Option<String>,
+    image_size: Option<CardSize>,
+    site_name: Option<String>,
+    kind: Option<String>,
+    twitter_card: TwitterCard,
+    twitter_site: Option<String>,
+    twitter_creator: Option<String>,
+}
+
+impl SocialMeta {
+    /// Sets the title, the document title by default.
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Gets the title.
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Sets the description, the document description by default.
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Gets the description.
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Sets the canonical URL of the page.
+    pub fn url(mut self, url: impl ToString) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    /// Gets the canonical URL of the page.
+    pub fn get_url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Sets the absolute URL of the card image.
+    pub fn image(mut self, image: impl ToString) -> Self {
+        self.image = Some(image.to_string());
+        self
+    }
+
+    /// Gets the URL of the card image.
+    pub fn get_image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+
+    /// Sets the alternative text of the card image.
+    pub fn image_alt(mut self, image_alt: impl ToString) -> Self {
+        self.image_alt = Some(image_alt.to_string());
+        self
+    }
+
+    /// Gets the alternative text of the card image.
+    pub fn get_image_alt(&self) -> Option<&str> {
+        self.image_alt.as_deref()
+    }
+
+    /// Sets the size of the card image, e.g. [OPEN_GRAPH_CARD_SIZE].
+    pub fn image_size(mut self, image_size: CardSize) -> Self {
+        self.image_size = Some(image_size);
+        self
+    }
+
+    /// Gets the size of the card image.
+    pub fn get_image_size(&self) -> Option<CardSize> {
+        self.image_size
+    }
+
+    /// Sets the name of the site.
+    pub fn site_name(mut self, site_name: impl ToString) -> Self {
+        self.site_name = Some(site_name.to_string());
+        self
+    }
+
+    /// Gets the name of the site.
+    pub fn get_site_name(&self) -> Option<&str> {
+        self.site_name.as_deref()
+    }
+
+    /// Sets the Open Graph type (`og:type`), `website` by default.
+    pub fn kind(mut self, kind: impl ToString) -> Self {
+        self.kind = Some(kind.to_string());
+        self
+    }
+
+    /// Gets the Open Graph type.
+    pub fn get_kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or("website")
+    }
+
+    /// Sets the type of the Twitter card.
+    pub fn twitter_card(mut self, twitter_card: TwitterCard) -> Self {
+        self.twitter_card = twitter_card;
+        self
+    }
+
+    /// Gets the type of the Twitter card.
+    pub fn get_twitter_card(&self) -> TwitterCard {
+        self.twitter_card
+    }
+
+    /// Sets the Twitter handle of the site, e.g. `@zen_rs`.
+    pub fn twitter_site(mut self, twitter_site: impl ToString) -> Self {
+        self.twitter_site = Some(twitter_site.to_string());
+        self
+    }
+
+    /// Gets the Twitter handle of the site.
+    pub fn get_twitter_site(&self) -> Option<&str> {
+        self.twitter_site.as_deref()
+    }
+
+    /// Sets the Twitter handle of the author.
+    pub fn twitter_creator(mut self, twitter_creator: impl ToString) -> Self {
+        self.twitter_creator = Some(twitter_creator.to_string());
+        self
+    }
+
+    /// Gets the Twitter handle of the author.
+    pub fn get_twitter_creator(&self) -> Option<&str> {
+        self.twitter_creator.as_deref()
+    }
+}
+
+// Social-related methods
+impl HtmlBuilder {
+    /// Sets the Open Graph and Twitter meta of the page.
+    pub fn social(mut self, social: SocialMeta) -> Self {
+        self.social = Some(social);
+        self
+    }
+
+    /// Gets the Open Graph and Twitter meta of the page.
+    pub fn get_social(&self) -> Option<&SocialMeta> {
+        self.social.as_ref()
+    }
+
+    /// Builds the `og:*` and `twitter:*` meta tags, empty without [HtmlBuilder::social].
+    pub fn build_social(&self) -> String {
+        let Some(social) = self.get_social() else {
+            return String::new();
+        };
+        let title = social.get_title().or(self.get_title());
+        let description = social.get_description().or(self.get_description());
+        let (width, height) = social.get_image_size().unzip();
+        let (width, height) = (width.map(|x| x.to_string()), height.map(|x| x.to_string()));
+        let og = [
+            ("og:type", Some(social.get_kind())),
+            ("og:title", title),
+            ("og:description", description),
+            ("og:url", social.get_url()),
+            ("og:site_name", social.get_site_name()),
+            ("og:image", social.get_image()),
+            ("og:image:alt", social.get_image_alt()),
+            ("og:image:width", width.as_deref()),
+            ("og:image:height", height.as_deref()),
+        ];
+        let twitter_card = social.get_twitter_card().to_string();
+        let twitter = [
+            ("twitter:card", Some(twitter_card.as_str())),
+            ("twitter:title", title),
+            ("twitter:description", description),
+            ("twitter:image", social.get_image()),
+            ("twitter:image:alt", social.get_image_alt()),
+            ("twitter:site", social.get_twitter_site()),
+            ("twitter:creator", social.get_twitter_creator()),
+        ];
+        let og = og
+            .into_iter()
+            .filter_map(|(name, x)| Some(("property", name, x?)));
+        let twitter = twitter
+            .into_iter()
+            .filter_map(|(name, x)| Some(("name", name, x?)));
+        og.chain(twitter)
+            .map(|(attribute, name, content)| {
+                format!(
+                    r#"<meta {attribute}="{name}" content="{}">"#,
+                    escape_attribute(content)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_social {
+    use crate::layouts::html::html_builder;
+
+    use super::{social_meta, TwitterCard, TWITTER_CARD_SIZE};
+
+    #[test]
+    fn meta() {
+        assert_eq!(html_builder().build_social(), "");
+
+        let html = html_builder()
+            .title("Page")
+            .description("About & more")
+            .social(
+                social_meta()
+                    .title("Card")
+                    .image("https://example.com/card.png")
+                    .image_alt("zen-rs logo")
+                    .image_size(TWITTER_CARD_SIZE)
+                    .twitter_card(TwitterCard::Summary)
+                    .twitter_site("@zen_rs"),
+            )
+            .build_as_html();
+        assert!(html.contains("<title>Page</title>"));
+        assert!(html.contains(
+            r#"<meta property="og:type" content="website"><meta property="og:title" content="Card"><meta property="og:description" content="About &amp; more">"#
+        ));
+        assert!(html.contains(r#"<meta property="og:image:height" content="600">"#));
+        assert!(html.contains(r#"<meta name="twitter:card" content="summary">"#));
+        assert!(html.contains(r#"<meta name="twitter:image:alt" content="zen-rs logo">"#));
+        assert!(html.contains(r#"<meta name="twitter:site" content="@zen_rs">"#));
+        assert!(!html.contains("og:url"));
+    }
+}
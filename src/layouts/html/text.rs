@@ -3,14 +3,44 @@
 //! function's behavior.
 //!
 //! The `text_html` function takes a `Text` component and generates a complete
-//! HTML tag (either a `<div>` or `<a>`) with applied CSS styles for foreground
+//! HTML tag (the tag of its role, e.g. `<h1>`, `<div>` or `<a>` for links) with applied CSS styles for foreground
 //! and background colors, font size, weight, style, and family. It also supports
 //! optional linking by generating a valid `href` attribute when a link is provided.
 //!
 //! The test module ensures that the `text_html` function works as expected by
 //! rendering a few `Text` components and printing the resulting HTML.
 
-use crate::components::text::Text;
+use super::{align_css, attributes_html, color_value, font_size_value, font_value};
+use crate::{
+    aspects::{font_stack, FontFamily, TextRole},
+    components::text::Text,
+};
+
+/// Renders a [FontFamily] stack into a CSS `font-family` value.
+///
+/// Empty names are skipped, e.g. `'Inter', 'Roboto', sans-serif`
+pub fn font_stack_css(family: &FontFamily) -> String {
+    let stack: Vec<String> = font_stack(family).map(|x| x.to_string()).collect();
+    stack.join(", ")
+}
+
+/// Maps [TextRole] onto the HTML tag.
+pub(crate) fn text_tag(role: &TextRole) -> &'static str {
+    match role {
+        TextRole::Generic => "div",
+        TextRole::H1 => "h1",
+        TextRole::H2 => "h2",
+        TextRole::H3 => "h3",
+        TextRole::H4 => "h4",
+        TextRole::H5 => "h5",
+        TextRole::H6 => "h6",
+        TextRole::Paragraph => "p",
+        TextRole::Span => "span",
+        TextRole::Strong => "strong",
+        TextRole::Code => "code",
+        TextRole::Blockquote => "blockquote",
+    }
+}
 
 /// Renders a `Text` component into an HTML string with applied styles.
 ///
@@ -27,81 +57,152 @@ use crate::components::text::Text;
 ///
 /// # Example
 /// ```rust
-/// let text_component = Text::new()
+/// use zen_rs::{components::text, layouts::html::text_html};
+///
+/// let text_component = text()
 ///     .content("Hello, World!")
 ///     .foreground_color((255, 0, 0, 1))
 ///     .background_color((0, 0, 0, 0))
 ///     .size(16);
 /// let html = text_html(&text_component);
-/// assert_eq!(html, r#"<div style="color: rgba(255, 0, 0, 1); background-color: rgba(0, 0, 0, 0); font-size: 16px; font-family: 'CustomFont', Arial;">Hello, World!</div>"#);
+/// assert!(html.starts_with("<div "));
+/// assert!(html.contains("color: rgba(255, 0, 0, 1);"));
+/// assert!(html.contains("font-size: 16px;"));
+/// assert!(html.ends_with(">Hello, World!</div>"));
 /// ```
 pub fn text_html(component: &Text) -> String {
     // data
-    let (f_red, f_green, f_blue, f_alpha) = component.get_foreground_color();
-    let (b_red, b_green, b_blue, b_alpha) = component.get_background_color();
-    let (size, weight, s, u, i, (custom_font, default_font)) = component.get_font();
-
-    // tag
-    let tag = if component.get_link().is_some() {
-        "a"
-    } else {
-        "div"
-    };
+    let fg = component.get_foreground_color();
+    let bg = component.get_background_color();
+    let (size, weight, s, u, i, font_family) = component.get_font();
 
-    // link (href)
-    let href = if let Some(href) = component.get_link() {
-        format!(r#"href="{href}""#)
-    } else {
-        "".to_string()
+    // tag, links of semantic texts are nested into the tag
+    let role = component.get_role();
+    let (tag, href) = match component.get_link() {
+        Some(href) if matches!(role, TextRole::Generic | TextRole::Span) => {
+            ("a", format!(r#"href="{href}""#))
+        }
+        _ => (text_tag(&role), "".to_string()),
     };
 
     // content
-    let content = component.get_content();
+    let content = match component.get_link() {
+        Some(href) if tag != "a" => format!(r#"<a href="{href}">{}</a>"#, component.get_content()),
+        _ => component.get_content().to_string(),
+    };
 
     // css
-    let font_style = {
-        let u64_weight: u64 = (*weight).into();
-        let fw = |s: u64| format!("font-weight: {s};");
-        match weight {
-            crate::aspects::Weight::Heavy => fw(u64_weight),
-            crate::aspects::Weight::ExtraBold => fw(u64_weight),
-            crate::aspects::Weight::Bold => fw(u64_weight),
-            crate::aspects::Weight::SemiBold => fw(u64_weight),
-            crate::aspects::Weight::Medium => fw(u64_weight),
-            crate::aspects::Weight::Normal => fw(u64_weight),
-            crate::aspects::Weight::Light => fw(u64_weight),
-            crate::aspects::Weight::ExtraLight => fw(u64_weight),
-            crate::aspects::Weight::Thin => fw(u64_weight),
-        }
-    };
-    let font_size = format!("font-size: {size}px;");
+    let font_style = format!("font-weight: {weight};");
+    let font_size = format!(
+        "font-size: {};",
+        font_size_value(component.get_size_token(), *size)
+    );
     let font_sui = {
         let mut font_sui = String::new();
         if *i {
             font_sui.push_str("font-style: italic;")
         }
-        if *u {
-            font_sui.push_str("text-decoration-line: underline;")
-        }
-        if *s {
-            font_sui.push_str("text-decoration-line: line-through;")
+        let lines = match (*u, *s) {
+            (true, true) => Some("underline line-through"),
+            (true, false) => Some("underline"),
+            (false, true) => Some("line-through"),
+            (false, false) => None,
+        };
+        if let Some(lines) = lines {
+            let (color, thickness, style) = component.get_decoration();
+            font_sui.push_str(&format!("text-decoration-line: {lines};"));
+            font_sui.push_str(&format!("text-decoration-style: {style};"));
+            if let Some((d_red, d_green, d_blue, d_alpha)) = color {
+                font_sui.push_str(&format!(
+                    "text-decoration-color: rgba({d_red}, {d_green}, {d_blue}, {d_alpha});"
+                ));
+            }
+            if let Some(thickness) = thickness {
+                font_sui.push_str(&format!("text-decoration-thickness: {thickness}px;"));
+            }
         }
         font_sui
     };
-    let bg_color = format!("background-color: rgba({b_red}, {b_green}, {b_blue}, {b_alpha});");
-    let fg_color = format!("color: rgba({f_red}, {f_green}, {f_blue}, {f_alpha});");
-    let font_family = format!(r#"font-family: '{custom_font}', {default_font};"#);
+    let bg_color = format!(
+        "background-color: {};",
+        color_value(
+            component.get_background_token(),
+            &bg,
+            component.get_background_color_dark()
+        )
+    );
+    let fg_color = format!(
+        "color: {};",
+        color_value(
+            component.get_foreground_token(),
+            &fg,
+            component.get_foreground_color_dark()
+        )
+    );
+    let font_family = format!(
+        "font-family: {};",
+        font_value(component.get_font_token(), font_stack_css(font_family))
+    );
+    let typography = {
+        let (line_height, letter_spacing, word_spacing, text_align, text_transform) =
+            component.get_typography();
+        let mut typography = String::new();
+        if let Some(line_height) = line_height {
+            typography.push_str(&format!("line-height: {line_height};"))
+        }
+        if let Some(letter_spacing) = letter_spacing {
+            typography.push_str(&format!("letter-spacing: {letter_spacing}px;"))
+        }
+        if let Some(word_spacing) = word_spacing {
+            typography.push_str(&format!("word-spacing: {word_spacing}px;"))
+        }
+        if let Some(text_align) = text_align {
+            typography.push_str(&format!("text-align: {text_align};"))
+        }
+        if *text_transform != crate::aspects::TextTransform::None {
+            typography.push_str(&format!("text-transform: {text_transform};"))
+        }
+        typography
+    };
+    let truncate = match component.get_truncate() {
+        crate::aspects::Truncate::None | crate::aspects::Truncate::Lines(0) => "".to_string(),
+        crate::aspects::Truncate::Ellipsis => {
+            "min-width: 0; overflow: hidden; white-space: nowrap; text-overflow: ellipsis;"
+                .to_string()
+        }
+        crate::aspects::Truncate::Lines(lines) => format!(
+            "min-width: 0; overflow: hidden; display: -webkit-box; -webkit-box-orient: vertical; -webkit-line-clamp: {lines}; line-clamp: {lines};"
+        ),
+    };
+    let align_self = component
+        .get_align_self()
+        .map(|x| format!("align-self: {};", align_css(&x)))
+        .unwrap_or_default();
 
     let css = format!(
-        r#"style="{bg_color} {fg_color} {font_size} {font_sui} {font_style} {font_family}""#
+        r#"style="{bg_color} {fg_color} {font_size} {font_sui} {font_style} {font_family} {typography} {truncate} {align_self}""#
     );
     // out
-    format!("<{tag} {css} {href}>{content}</{tag}>")
+    let attributes = attributes_html(
+        component.get_id(),
+        component.get_classes(),
+        component.get_attributes(),
+    );
+    format!("<{tag} {css} {href}{attributes}>{content}</{tag}>")
 }
 
 #[cfg(test)]
 mod test_text {
-    use crate::components::text::text;
+    use crate::{
+        aspects::{
+            ColorToken, DecorationStyle, DefaultFontFamily, TextAlign, TextRole, TextTransform,
+            Weight,
+        },
+        components::text::{
+            h::{mono_text_xs, text_3xl, text_4xl, text_9xl, text_base, text_lg, text_xl},
+            text,
+        },
+    };
 
     use super::text_html;
 
@@ -123,4 +224,140 @@ mod test_text {
         let html = text_html(&text2);
         println!("OUT: {}", html);
     }
+
+    #[test]
+    fn truncate() {
+        let html = text_html(&text().content("zen-rs").ellipsis());
+        assert!(html.contains("white-space: nowrap;"));
+        assert!(html.contains("text-overflow: ellipsis;"));
+        let html = text_html(&text().content("zen-rs").line_clamp(2));
+        assert!(html.contains("-webkit-line-clamp: 2;"));
+        assert!(html.contains("line-clamp: 2;"));
+        let html = text_html(&text().content("zen-rs").line_clamp(0));
+        assert!(!html.contains("line-clamp"));
+        assert!(!html.contains("overflow"));
+        let html = text_html(&text().content("zen-rs"));
+        assert!(!html.contains("overflow"));
+    }
+
+    #[test]
+    fn typography() {
+        let html = text_html(
+            &text()
+                .content("zen-rs")
+                .line_height(1.25)
+                .letter_spacing(-0.5)
+                .word_spacing(2.0)
+                .text_align(TextAlign::Justify)
+                .text_transform(TextTransform::Uppercase),
+        );
+        assert!(html.contains("line-height: 1.25;"));
+        assert!(html.contains("letter-spacing: -0.5px;"));
+        assert!(html.contains("word-spacing: 2px;"));
+        assert!(html.contains("text-align: justify;"));
+        assert!(html.contains("text-transform: uppercase;"));
+        let html = text_html(&text().content("zen-rs"));
+        assert!(!html.contains("line-height"));
+        assert!(!html.contains("text-transform"));
+    }
+
+    #[test]
+    fn decoration() {
+        let html = text_html(
+            &text()
+                .content("zen-rs")
+                .is_underline()
+                .is_strikeout()
+                .decoration_style(DecorationStyle::Wavy)
+                .decoration_color((255, 0, 0, 100))
+                .decoration_thickness(2),
+        );
+        assert_eq!(html.matches("text-decoration-line").count(), 1);
+        assert!(html.contains("text-decoration-line: underline line-through;"));
+        assert!(html.contains("text-decoration-style: wavy;"));
+        assert!(html.contains("text-decoration-color: rgba(255, 0, 0, 100);"));
+        assert!(html.contains("text-decoration-thickness: 2px;"));
+        let html = text_html(
+            &text()
+                .content("zen-rs")
+                .decoration_style(DecorationStyle::Dashed),
+        );
+        assert!(!html.contains("text-decoration"));
+    }
+
+    #[test]
+    fn variable_weight() {
+        let weight = Weight::try_from(450).unwrap();
+        let html = text_html(&text().content("zen-rs").font_weight(weight));
+        assert!(html.contains("font-weight: 450;"));
+    }
+
+    #[test]
+    fn font_family() {
+        let html = text_html(&text().content("zen-rs"));
+        assert!(html.contains("font-family: sans-serif;"));
+        assert!(!html.contains("''"));
+        let html = text_html(
+            &text()
+                .content("zen-rs")
+                .font_custom("Inter")
+                .font_fallback("")
+                .font_fallback("Roboto")
+                .font_default(DefaultFontFamily::Monospace),
+        );
+        assert!(html.contains("font-family: 'Inter', 'Roboto', monospace;"));
+        // font_custom replaces the stack
+        let html = text_html(&text().font_custom("Inter").font_custom("Roboto"));
+        assert!(html.contains("font-family: 'Roboto', sans-serif;"));
+        // font_fallbacks appends like font_fallback
+        let html = text_html(
+            &text()
+                .font_custom("Inter")
+                .font_fallbacks(["Roboto", "Arial"])
+                .font_fallback("Helvetica"),
+        );
+        assert!(html.contains("font-family: 'Inter', 'Roboto', 'Arial', 'Helvetica', sans-serif;"));
+    }
+
+    #[test]
+    fn tokens() {
+        let html = text_html(&text_xl("zen-rs"));
+        assert!(html.contains("font-size: var(--zen-font-size-xl, 20px);"));
+        assert!(html.contains("color: var(--zen-color-foreground, rgba(0, 0, 0, 100));"));
+        let html = text_html(&mono_text_xs("zen-rs").background_token(ColorToken::Surface));
+        assert!(html.contains("font-family: var(--zen-font-mono, monospace);"));
+        assert!(html.contains("background-color: var(--zen-color-surface, rgba(0, 0, 0, 0));"));
+        // raw values set after a preset override its tokens
+        let html = text_html(
+            &text_xl("zen-rs")
+                .size(50)
+                .foreground_color((255, 0, 0, 100))
+                .font_custom("Inter"),
+        );
+        assert!(html.contains("font-size: 50px;"));
+        assert!(html.contains("color: rgba(255, 0, 0, 100);"));
+        assert!(html.contains("font-family: 'Inter', sans-serif;"));
+        assert!(!html.contains("var(--zen-"));
+    }
+
+    #[test]
+    fn role() {
+        let html = text_html(&text_xl("Repos"));
+        assert!(html.starts_with("<h4 ") && html.ends_with(">Repos</h4>"));
+        assert!(text_html(&mono_text_xs("gh")).starts_with("<code "));
+        assert!(text_html(&text_9xl("Zen")).starts_with("<h1 "));
+        assert!(text_html(&text_4xl("Zen")).starts_with("<h1 "));
+        assert!(text_html(&text_3xl("Zen")).starts_with("<h2 "));
+        assert!(text_html(&text_lg("Zen")).starts_with("<h5 "));
+        assert!(text_html(&text_base("Zen")).starts_with("<p "));
+        let html = text_html(&text_xl("Repos").role(TextRole::Generic));
+        assert!(html.starts_with("<div "));
+        let html = text_html(&text().content("Docs").link("/docs"));
+        assert!(html.starts_with("<a ") && html.contains(r#"href="/docs""#));
+        let html = text_html(&text().content("Docs").link("/docs").role(TextRole::H1));
+        assert!(html.starts_with("<h1 "));
+        assert!(html.ends_with(r#"><a href="/docs">Docs</a></h1>"#));
+        let html = text_html(&text().role(TextRole::Blockquote));
+        assert!(html.starts_with("<blockquote ") && html.ends_with("</blockquote>"));
+    }
 }
@@ -0,0 +1,337 @@
+//! This module contains the `theme_css` function for rendering a `Theme` into
+//! CSS custom properties, and helpers which reference them from components.
+//!
+//! Every token is exposed as `--zen-<kind>-<token>` variable, e.g.
+//! `--zen-color-primary` or `--zen-space-md`. Components which use a token
+//! render `var(--zen-...)` with their raw value as fallback, so the output
+//! stays valid without the theme rules.
+//!
+//! `HtmlBuilder` defines the variables only when the tree uses tokens or dark
+//! colors, on a `zen-theme-<hash>` class of the rendered root instead of
+//! `:root`, so several outputs (even with different themes) can be inlined
+//! into one page without overwriting each other or leaking into it.
+//!
+//! Dark variants of component colors are exposed as `--zen-dark-<r>-<g>-<b>-<a>`
+//! variables, which are defined only for the dark color scheme, so the light
+//! color is used as fallback otherwise.
+
+use std::{collections::BTreeSet, fmt::Display};
+
+use super::font_stack_css;
+use crate::{
+    aspects::{
+        Color, ColorToken, DarkColor, FontSizeToken, FontToken, RadiusToken, Size, SpaceToken,
+    },
+    components::Components,
+    theme::Theme,
+};
+
+/// Renders every token of a `Theme` as CSS custom properties (without selector).
+pub fn theme_variables(theme: &Theme) -> String {
+    let mut out = Vec::new();
+    for (token, color) in theme.get_colors() {
+        out.push(format!("--zen-color-{token}: {};", rgba(color)));
+    }
+    for (token, size) in theme.get_spaces() {
+        out.push(format!("--zen-space-{token}: {size}px;"));
+    }
+    for (token, radius) in theme.get_radii() {
+        out.push(format!("--zen-radius-{token}: {radius}px;"));
+    }
+    for (token, size) in theme.get_font_sizes() {
+        out.push(format!("--zen-font-size-{token}: {size}px;"));
+    }
+    for (token, family) in theme.get_fonts() {
+        out.push(format!("--zen-font-{token}: {};", font_stack_css(family)));
+    }
+    out.join(" ")
+}
+
+/// Renders a `Theme` into a `:root` rule with CSS custom properties.
+///
+/// `HtmlBuilder` scopes the variables to the rendered root instead, see
+/// `HtmlBuilder::get_theme_scope`.
+///
+/// # Example
+/// ```rust
+/// use zen_rs::{layouts::html::theme_css, theme::theme};
+///
+/// let css = theme_css(&theme());
+/// assert!(css.starts_with(":root { --zen-color-background: rgba(255, 255, 255, 100);"));
+/// assert!(css.contains("--zen-space-md: 16px;"));
+/// ```
+pub fn theme_css(theme: &Theme) -> String {
+    format!(":root {{ {} }}", theme_variables(theme))
+}
+
+/// Whether any component of the tree references a theme token.
+pub fn uses_tokens(component: &Components) -> bool {
+    component.fold(false, |used, component| {
+        used || match component {
+            Components::Container(container) => {
+                container.get_background_token().is_some()
+                    || container.get_border_color_token().is_some()
+                    || container.get_border_radius_token().is_some()
+                    || container.get_gap_token().is_some()
+                    || container.get_padding_token().is_some()
+            }
+            Components::Text(text) => {
+                text.get_foreground_token().is_some()
+                    || text.get_background_token().is_some()
+                    || text.get_size_token().is_some()
+                    || text.get_font_token().is_some()
+            }
+            Components::Icon(_) | Components::Custom(_) => false,
+        }
+    })
+}
+
+/// Collects dark variants of every color in the component tree.
+pub fn dark_colors(component: &Components) -> BTreeSet<Color> {
+    let mut out = BTreeSet::new();
+    component.walk(|component| match component {
+        Components::Container(container) => {
+            out.extend(container.get_background_color_dark());
+            out.extend(container.get_border_color_dark());
+        }
+        Components::Text(text) => {
+            out.extend(text.get_foreground_color_dark());
+            out.extend(text.get_background_color_dark());
+        }
+        Components::Icon(icon) => {
+            out.extend(icon.get_foreground_color_dark());
+            out.extend(icon.get_background_color_dark());
+        }
+        // expanded by `walk`, their rendered tree is visited
+        Components::Custom(_) => {}
+    });
+    out
+}
+
+/// Renders dark variants of colors as CSS custom properties (without selector).
+pub fn dark_color_variables<'a>(colors: impl IntoIterator<Item = &'a Color>) -> String {
+    colors
+        .into_iter()
+        .map(|x| format!("{}: {};", dark_var_name(x), rgba(x)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Name of the CSS variable which holds the dark variant of a color.
+fn dark_var_name((red, green, blue, alpha): &Color) -> String {
+    format!("--zen-dark-{red}-{green}-{blue}-{alpha}")
+}
+
+/// CSS `rgba()` value of a color.
+pub(crate) fn rgba((red, green, blue, alpha): &Color) -> String {
+    format!("rgba({red}, {green}, {blue}, {alpha})")
+}
+
+/// CSS variable reference with fallback.
+fn var(kind: &str, token: impl Display, fallback: impl Display) -> String {
+    format!("var(--zen-{kind}-{token}, {fallback})")
+}
+
+/// Color value, referencing the theme palette if a token is set
+/// and the dark variant if it is set.
+pub(crate) fn color_value(token: Option<ColorToken>, color: &Color, dark: DarkColor) -> String {
+    let light = match token {
+        Some(token) => var("color", token, rgba(color)),
+        None => rgba(color),
+    };
+    dark_value(dark, light)
+}
+
+/// Color value referencing the dark variant if it is set, `light` otherwise.
+pub(crate) fn dark_value(dark: DarkColor, light: String) -> String {
+    match dark {
+        Some(dark) => format!("var({}, {light})", dark_var_name(&dark)),
+        None => light,
+    }
+}
+
+/// Spacing value, referencing the theme spacing scale if a token is set.
+pub(crate) fn space_value(token: Option<SpaceToken>, size: Size) -> String {
+    match token {
+        Some(token) => var("space", token, format!("{size}px")),
+        None => format!("{size}px"),
+    }
+}
+
+/// Border radius value, referencing the theme radius scale if a token is set.
+pub(crate) fn radius_value(token: Option<RadiusToken>, radius: Size) -> String {
+    match token {
+        Some(token) => var("radius", token, format!("{radius}px")),
+        None => format!("{radius}px"),
+    }
+}
+
+/// Font size value, referencing the theme type scale if a token is set.
+pub(crate) fn font_size_value(token: Option<FontSizeToken>, size: Size) -> String {
+    match token {
+        Some(token) => var("font-size", token, format!("{size}px")),
+        None => format!("{size}px"),
+    }
+}
+
+/// Font family value, referencing the theme fonts if a token is set.
+pub(crate) fn font_value(token: Option<FontToken>, stack: String) -> String {
+    match token {
+        Some(token) => var("font", token, stack),
+        None => stack,
+    }
+}
+
+#[cfg(test)]
+mod test_theme {
+    use crate::{
+        aspects::{ColorScheme, ColorToken, SpaceToken},
+        components::{h::text_base, icon, text, Component, Components},
+        dynamic_stack::vstack,
+        layouts::html::{dark_colors, html_builder, icon_html, text_html},
+        theme::Theme,
+    };
+
+    #[test]
+    fn switch_theme() {
+        let light = html_builder().component(text_base("zen-rs"));
+        let dark = light.clone().theme(Theme::dark());
+        // differently themed outputs don't share the scope of their variables
+        let (light_scope, dark_scope) = (light.get_theme_scope(), dark.get_theme_scope());
+        assert_ne!(light_scope, dark_scope);
+        let light_scope = light_scope.unwrap();
+        assert!(light
+            .render()
+            .contains(&format!(r#" class="{light_scope}""#)));
+        assert!(light
+            .build_style()
+            .contains(&format!("<style>.{light_scope} {{ --zen-color-background:")));
+        assert!(!light.build_style().contains(":root"));
+        assert!(light
+            .build_style()
+            .contains("--zen-color-foreground: rgba(0, 0, 0, 100);"));
+        assert!(dark
+            .build_style()
+            .contains("--zen-color-foreground: rgba(255, 255, 255, 100);"));
+    }
+
+    #[test]
+    fn custom_tokens() {
+        let theme = Theme::light()
+            .color(ColorToken::Primary, (1, 2, 3, 4))
+            .space(SpaceToken::Md, 12);
+        assert_eq!(html_builder().theme(theme.clone()).build_theme(), "");
+        let style = html_builder()
+            .theme(theme)
+            .component(text_base("zen-rs"))
+            .build_style();
+        assert!(style.contains("--zen-color-primary: rgba(1, 2, 3, 4);"));
+        assert!(style.contains("--zen-space-md: 12px;"));
+        assert!(style.contains("--zen-font-mono: monospace;"));
+    }
+
+    #[test]
+    fn dark_color_pair() {
+        let html = text_html(
+            &text()
+                .foreground_color((0, 0, 0, 100))
+                .foreground_color_dark((255, 255, 255, 100)),
+        );
+        assert!(html.contains("color: var(--zen-dark-255-255-255-100, rgba(0, 0, 0, 100));"));
+        let html = text_html(&text_base("zen-rs").foreground_color_dark((1, 1, 1, 1)));
+        assert!(html.contains(
+            "color: var(--zen-dark-1-1-1-1, var(--zen-color-foreground, rgba(0, 0, 0, 100)));"
+        ));
+    }
+
+    #[test]
+    fn prefers_color_scheme() {
+        let builder = html_builder();
+        assert!(!builder.build_theme().contains("@media"));
+        let builder = builder.component(
+            vstack((text().foreground_color_dark((255, 255, 255, 100)),))
+                .background_color_dark((0, 0, 0, 100)),
+        );
+        let css = builder.build_theme();
+        let scope = builder.get_theme_scope().unwrap();
+        assert!(builder.render().contains(&format!(r#" class="{scope}""#)));
+        assert_eq!(
+            css,
+            format!("@media (prefers-color-scheme: dark) {{ .{scope} {{ --zen-dark-0-0-0-100: rgba(0, 0, 0, 100); --zen-dark-255-255-255-100: rgba(255, 255, 255, 100); }} }}")
+        );
+        // the dark theme only matters for tokens
+        assert_eq!(builder.clone().dark_theme(Theme::dark()).build_theme(), css);
+        let builder = builder
+            .component(text_base("zen-rs"))
+            .dark_theme(Theme::dark());
+        let scope = builder.get_theme_scope().unwrap();
+        assert!(builder.build_theme().contains(&format!(
+            "@media (prefers-color-scheme: dark) {{ .{scope} {{ --zen-color-background: rgba(10, 10, 10, 100);"
+        )));
+    }
+
+    #[test]
+    fn icon_dark_color_pair() {
+        let icon = icon()
+            .foreground_color("#000")
+            .foreground_color_dark((255, 255, 255, 100));
+        let html = icon_html(&icon);
+        assert!(html.contains(r##"stroke="#000""##));
+        assert!(html.contains(r##"style="stroke: var(--zen-dark-255-255-255-100, #000);""##));
+        let colors = dark_colors(&vstack((icon.background_color_dark((1, 1, 1, 1)),)).into());
+        assert_eq!(colors.len(), 2);
+    }
+
+    #[test]
+    fn custom_dark_colors() {
+        #[derive(Debug)]
+        struct Card;
+        impl Component for Card {
+            fn render(&self) -> Components {
+                text().foreground_color_dark((1, 1, 1, 1)).into()
+            }
+        }
+        let tree: Components = vstack((Card,)).into();
+        assert!(
+            matches!(&tree, Components::Container(x) if matches!(x.get_components()[0], Components::Custom(_)))
+        );
+        assert_eq!(dark_colors(&tree).len(), 1);
+        assert_eq!(dark_colors(&Card.into()).len(), 1);
+    }
+
+    #[test]
+    fn forced_color_scheme() {
+        let builder = html_builder()
+            .component(text().foreground_color_dark((255, 255, 255, 100)))
+            .dark_theme(Theme::dark());
+        let light = builder
+            .clone()
+            .color_scheme(ColorScheme::Light)
+            .build_theme();
+        assert!(!light.contains("@media"));
+        assert!(!light.contains("--zen-dark-"));
+        let dark = builder.color_scheme(ColorScheme::Dark).build_theme();
+        assert!(!dark.contains("@media"));
+        assert!(dark.contains("--zen-dark-255-255-255-100: rgba(255, 255, 255, 100);"));
+        assert!(dark.ends_with("--zen-dark-255-255-255-100: rgba(255, 255, 255, 100); }"));
+    }
+
+    #[test]
+    fn pick_scheme() {
+        let text = text()
+            .foreground_color((0, 0, 0, 100))
+            .foreground_color_dark((255, 255, 255, 100));
+        assert_eq!(
+            text.get_foreground_color_for(ColorScheme::Light),
+            (0, 0, 0, 100)
+        );
+        assert_eq!(
+            text.get_foreground_color_for(ColorScheme::Dark),
+            (255, 255, 255, 100)
+        );
+        assert_eq!(
+            text.get_background_color_for(ColorScheme::Dark),
+            text.get_background_color()
+        );
+    }
+}
@@ -6,6 +6,8 @@
 //!   - [x] As text (HTML `<div>`)
 //!   - [x] As link (HTML , `<a>`)
 //! * [x] Icon
+//!   - [x] Tabler icons (feature `tabler`)
+//! * [x] Custom (user defined, [components::Component] trait)
 //!
 //! ## Available Renders
 //! * [x] HTML (custom implementation)
@@ -28,3 +30,5 @@ pub mod aspects;
 pub mod components;
 pub mod dynamic_stack;
 pub mod layouts;
+pub mod theme;
+mod view;
@@ -0,0 +1,190 @@
+//! Theme with design tokens
+//!
+//! A [Theme] holds the values of every token from [crate::aspects::token]:
+//! - palette ([ColorToken])
+//! - spacing scale ([SpaceToken])
+//! - border radius scale ([RadiusToken])
+//! - type scale ([FontSizeToken])
+//! - font families ([FontToken])
+//!
+//! Components reference tokens (e.g. [crate::components::Text::foreground_token]),
+//! layouts resolve them with the theme at render time.
+
+use std::collections::BTreeMap;
+
+use crate::aspects::{
+    Color, ColorToken, DefaultFontFamily, FontFamily, FontSizeToken, FontToken, Radius,
+    RadiusToken, Size, SpaceToken,
+};
+
+/// Returns a default (light) [Theme] instance.
+#[inline]
+pub fn theme() -> Theme {
+    Theme::default()
+}
+
+/// Values of the design tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Palette
+    colors: BTreeMap<ColorToken, Color>,
+    /// Spacing scale (in px)
+    spaces: BTreeMap<SpaceToken, Size>,
+    /// Border radius scale (in px)
+    radii: BTreeMap<RadiusToken, Radius>,
+    /// Type scale (in px)
+    font_sizes: BTreeMap<FontSizeToken, Size>,
+    /// Font families
+    fonts: BTreeMap<FontToken, FontFamily>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme {
+    /// Light theme, dark text on a white background
+    pub fn light() -> Self {
+        Self::with_palette([
+            (255, 255, 255, 100),
+            (245, 245, 245, 100),
+            (0, 0, 0, 100),
+            (115, 115, 115, 100),
+            (37, 99, 235, 100),
+            (22, 163, 74, 100),
+            (229, 229, 229, 100),
+        ])
+    }
+
+    /// Dark theme, light text on a black background
+    pub fn dark() -> Self {
+        Self::with_palette([
+            (10, 10, 10, 100),
+            (23, 23, 23, 100),
+            (255, 255, 255, 100),
+            (163, 163, 163, 100),
+            (96, 165, 250, 100),
+            (74, 222, 128, 100),
+            (38, 38, 38, 100),
+        ])
+    }
+
+    /// Theme with the default scales and the palette in order of [ColorToken::ALL]
+    fn with_palette(palette: [Color; 7]) -> Self {
+        let spaces = [0, 4, 8, 16, 24, 32, 48];
+        let radii = [0, 4, 8, 16, 9999];
+        let font_sizes = [12, 14, 16, 18, 20, 24, 30, 36, 48, 60, 72, 96, 128];
+        let fonts = [
+            DefaultFontFamily::SansSerif,
+            DefaultFontFamily::SansSerif,
+            DefaultFontFamily::Monospace,
+        ];
+        Self {
+            colors: ColorToken::ALL.into_iter().zip(palette).collect(),
+            spaces: SpaceToken::ALL.into_iter().zip(spaces).collect(),
+            radii: RadiusToken::ALL.into_iter().zip(radii).collect(),
+            font_sizes: FontSizeToken::ALL.into_iter().zip(font_sizes).collect(),
+            fonts: FontToken::ALL
+                .into_iter()
+                .zip(fonts.map(|x| (vec![], x)))
+                .collect(),
+        }
+    }
+
+    /// Sets the color of a palette token.
+    #[inline]
+    pub fn color(mut self, token: ColorToken, color: Color) -> Self {
+        self.colors.insert(token, color);
+        self
+    }
+
+    /// Sets the size of a spacing token.
+    #[inline]
+    pub fn space(mut self, token: SpaceToken, size: Size) -> Self {
+        self.spaces.insert(token, size);
+        self
+    }
+
+    /// Sets the size of a border radius token.
+    #[inline]
+    pub fn radius(mut self, token: RadiusToken, radius: Radius) -> Self {
+        self.radii.insert(token, radius);
+        self
+    }
+
+    /// Sets the size of a type scale token.
+    #[inline]
+    pub fn font_size(mut self, token: FontSizeToken, size: Size) -> Self {
+        self.font_sizes.insert(token, size);
+        self
+    }
+
+    /// Sets the font family stack of a font token.
+    #[inline]
+    pub fn font(mut self, token: FontToken, family: FontFamily) -> Self {
+        self.fonts.insert(token, family);
+        self
+    }
+
+    /// Retrieves the color of a palette token.
+    #[inline]
+    pub fn get_color(&self, token: ColorToken) -> Color {
+        self.colors.get(&token).copied().unwrap_or_default()
+    }
+
+    /// Retrieves the size of a spacing token.
+    #[inline]
+    pub fn get_space(&self, token: SpaceToken) -> Size {
+        self.spaces.get(&token).copied().unwrap_or_default()
+    }
+
+    /// Retrieves the size of a border radius token.
+    #[inline]
+    pub fn get_radius(&self, token: RadiusToken) -> Radius {
+        self.radii.get(&token).copied().unwrap_or_default()
+    }
+
+    /// Retrieves the size of a type scale token.
+    #[inline]
+    pub fn get_font_size(&self, token: FontSizeToken) -> Size {
+        self.font_sizes.get(&token).copied().unwrap_or_default()
+    }
+
+    /// Retrieves the font family stack of a font token.
+    #[inline]
+    pub fn get_font(&self, token: FontToken) -> FontFamily {
+        self.fonts.get(&token).cloned().unwrap_or_default()
+    }
+
+    /// Retrieves the whole palette.
+    #[inline]
+    pub fn get_colors(&self) -> &BTreeMap<ColorToken, Color> {
+        &self.colors
+    }
+
+    /// Retrieves the whole spacing scale.
+    #[inline]
+    pub fn get_spaces(&self) -> &BTreeMap<SpaceToken, Size> {
+        &self.spaces
+    }
+
+    /// Retrieves the whole border radius scale.
+    #[inline]
+    pub fn get_radii(&self) -> &BTreeMap<RadiusToken, Radius> {
+        &self.radii
+    }
+
+    /// Retrieves the whole type scale.
+    #[inline]
+    pub fn get_font_sizes(&self) -> &BTreeMap<FontSizeToken, Size> {
+        &self.font_sizes
+    }
+
+    /// Retrieves every font family stack.
+    #[inline]
+    pub fn get_fonts(&self) -> &BTreeMap<FontToken, FontFamily> {
+        &self.fonts
+    }
+}
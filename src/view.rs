@@ -0,0 +1,116 @@
+//! `view!` macro for declaring component trees
+//!
+//! Expands into the builders of [crate::components] and [crate::dynamic_stack].
+//!
+//! Syntax of a node (siblings are separated by commas):
+//! - `name(args) [attrs]`: calls `name(args)`, parentheses are optional without args
+//! - `name [attrs] { children }`: calls `name((children))`, e.g. [crate::dynamic_stack::vstack]
+//! - `if (condition) { children } else { children }`: `else` is optional
+//! - `for pattern in (iterator) { children }`
+//! - `{ expression }`: any [crate::dynamic_stack::Dynamic] value
+//!
+//! Attributes map onto builder methods: `key: value` calls `.key(value)`,
+//! `key` calls `.key()`.
+//!
+//! # Example
+//! ```rust
+//! use zen_rs::{
+//!     aspects::Align,
+//!     components::{github::github_outlined, h::mono_text_xl, text},
+//!     dynamic_stack::vstack,
+//!     view,
+//! };
+//!
+//! let repos = ["zen-rs", "rui"];
+//! let show_logo = true;
+//! let page = view! {
+//!     vstack [flex, gap: 4, align_items: Align::Center] {
+//!         if (show_logo) { github_outlined [size: 24] },
+//!         mono_text_xl("gh"),
+//!         for repo in (repos) {
+//!             text [content: repo, size: 14]
+//!         },
+//!         { text().content("footer") },
+//!     }
+//! };
+//! assert_eq!(page.get_components().len(), 5);
+//! ```
+
+/// Declares a component tree, see the [module documentation](crate::view).
+#[macro_export]
+macro_rules! view {
+    // nodes: `@nodes mode [parsed nodes] rest`
+    (@nodes $mode:tt [$($acc:expr,)*]) => {
+        $crate::view!(@finish $mode [$($acc,)*])
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        if ($cond:expr) { $($then:tt)* } else { $($else:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)* if $cond {
+            $crate::dynamic_stack::components($crate::view!(@nodes children [] $($then)*))
+        } else {
+            $crate::dynamic_stack::components($crate::view!(@nodes children [] $($else)*))
+        },] $($($rest)*)?)
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        if ($cond:expr) { $($then:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)*
+            ($cond).then(|| $crate::view!(@nodes children [] $($then)*)),
+        ] $($($rest)*)?)
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        for $pat:pat in ($iter:expr) { $($body:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)*
+            $crate::dynamic_stack::each(::core::iter::IntoIterator::into_iter($iter)
+                .map(|$pat| $crate::view!(@nodes children [] $($body)*))),
+        ] $($($rest)*)?)
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        { $node:expr } $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)* $node,] $($($rest)*)?)
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        $($name:ident)::+ $([$($attr:tt)*])? { $($children:tt)* } $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)*
+            $crate::view!(@attrs ($($name)::+($crate::view!(@nodes children [] $($children)*)))
+                $($($attr)*)?),
+        ] $($($rest)*)?)
+    };
+    (@nodes $mode:tt [$($acc:expr,)*]
+        $($name:ident)::+ $(($($arg:expr),* $(,)?))? $([$($attr:tt)*])? $(, $($rest:tt)*)?
+    ) => {
+        $crate::view!(@nodes $mode [$($acc,)*
+            $crate::view!(@attrs ($($name)::+($($($arg),*)?)) $($($attr)*)?),
+        ] $($($rest)*)?)
+    };
+
+    // result of the nodes
+    (@finish root [$node:expr,]) => {
+        $node
+    };
+    (@finish root [$($node:expr,)*]) => {
+        ::core::compile_error!("view! expects a single root component")
+    };
+    (@finish children [$($node:expr,)*]) => {
+        ($($node,)*)
+    };
+
+    // attributes: `@attrs (component) attrs`
+    (@attrs ($node:expr)) => {
+        $node
+    };
+    (@attrs ($node:expr) $key:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::view!(@attrs ($node.$key($value)) $($($rest)*)?)
+    };
+    (@attrs ($node:expr) $key:ident $(, $($rest:tt)*)?) => {
+        $crate::view!(@attrs ($node.$key()) $($($rest)*)?)
+    };
+
+    ($($nodes:tt)+) => {
+        $crate::view!(@nodes root [] $($nodes)+)
+    };
+}
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod test_html {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use zen_rs::{
-        aspects::Align,
-        components::{github::github_outlined, h::mono_text_xl, Components},
+        aspects::{Align, Justify},
+        components::{github::github_outlined, h::mono_text_xl, text, Component, Components},
         dynamic_stack::vstack,
         layouts::html::HtmlBuilder,
+        view,
     };
 
     fn complited_component() -> impl Into<Components> {
@@ -16,7 +19,7 @@ mod test_html {
         .background_color((30, 200, 100, 100))
         .gap(4)
         .padding(16)
-        .align_content(zen_rs::aspects::Align::Center)
+        .align_items(Align::Center)
         .flex(),))
         .flex()
         .border_size_t(2)
@@ -27,10 +30,105 @@ mod test_html {
         .width_full()
         .height_full()
         .align_items(Align::Center)
-        .align_content(Align::Center)
+        .justify_content(Justify::Center)
         .background_color((0, 0, 0, 100))
     }
 
+    #[test]
+    fn view() {
+        let view = view! {
+            vstack [
+                flex,
+                width_full,
+                height_full,
+                align_items: Align::Center,
+                justify_content: Justify::Center,
+                background_color: (0, 0, 0, 100),
+            ] {
+                vstack [
+                    flex,
+                    border_size_t: 2,
+                    border_size_b: 2,
+                    border_color: (255, 255, 255, 100),
+                    border_radius: 18,
+                ] {
+                    vstack [
+                        background_color: (30, 200, 100, 100),
+                        gap: 4,
+                        padding: 16,
+                        align_items: Align::Center,
+                        flex,
+                    ] {
+                        github_outlined,
+                        mono_text_xl("gh"),
+                        mono_text_xl("not ph"),
+                    }
+                }
+            }
+        };
+        assert_eq!(Components::from(view), complited_component().into());
+    }
+
+    #[derive(Debug)]
+    struct RepoCard {
+        name: &'static str,
+    }
+
+    impl Component for RepoCard {
+        fn render(&self) -> Components {
+            vstack((
+                github_outlined(),
+                text()
+                    .content(self.name)
+                    .foreground_color_dark((1, 2, 3, 100)),
+            ))
+            .into()
+        }
+    }
+
+    #[test]
+    fn custom() {
+        let repos = ["zen-rs", "rui"].map(|name| RepoCard { name });
+        let html = HtmlBuilder::default()
+            .component(vstack(repos))
+            .icon_sprites(true)
+            .build();
+        assert!(html.contains("zen-rs") && html.contains("rui"));
+        assert_eq!(html.matches("<symbol").count(), 1);
+        assert_eq!(html.matches("<use").count(), 2);
+        assert!(html.contains("--zen-dark-1-2-3-100"));
+        let card = Components::from(RepoCard { name: "a" });
+        assert_eq!(card, card.clone());
+        assert_eq!(card, RepoCard { name: "a" }.into());
+        assert_ne!(card, RepoCard { name: "b" }.into());
+        assert_eq!(
+            card.expand(),
+            Components::from(RepoCard { name: "a" }).expand()
+        );
+    }
+
+    #[test]
+    fn custom_rendered_once() {
+        static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug)]
+        struct Counted;
+
+        impl Component for Counted {
+            fn render(&self) -> Components {
+                RENDERS.fetch_add(1, Ordering::SeqCst);
+                text().content("counted").into()
+            }
+        }
+
+        let html = HtmlBuilder::default()
+            .component(vstack((Counted,)))
+            .icon_sprites(true)
+            .build_as_html();
+        assert!(html.contains("counted"));
+        assert_eq!(RENDERS.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn render() {
         let cp = complited_component();